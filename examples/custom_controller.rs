@@ -20,7 +20,7 @@ impl Controller for BangBangController {
         } else {
             0.0
         };
-        GncCommand { gimbal_y: gy, gimbal_z: 0.0 }
+        GncCommand { gimbal_y: gy, gimbal_z: 0.0, throttle: 1.0 }
     }
 
     fn name(&self) -> &str {
@@ -38,7 +38,7 @@ fn main() {
 
     // Propagate 3 orbits
     let duration = 3.0 * period;
-    let traj = orbital::propagate_orbit(&initial, 1.0, duration, true);
+    let traj = orbital::propagate_orbit(&initial, 1.0, duration, true, None);
 
     println!("Propagated {:.1} orbits ({:.0} seconds, {} steps)",
         duration / period, duration, traj.len());
@@ -0,0 +1,7 @@
+pub mod state;
+pub mod sixdof;
+pub mod turbulence;
+
+pub use state::{Deriv, GncCommand, LaunchConfig, SimConfig, State, WindModel, EARTH_RADIUS, G0};
+pub use sixdof::{derivatives, derivatives_with_wind};
+pub use turbulence::{DrydenField, SteadyWind, TurbulenceConfig, WindField};
@@ -1,7 +1,7 @@
 use nalgebra::{Quaternion, Vector3};
 
-use crate::physics::atmosphere;
-use crate::dynamics::state::{Deriv, GncCommand, State, EARTH_RADIUS, G0};
+use crate::physics::{aerodynamics, atmosphere};
+use crate::dynamics::state::{Deriv, GncCommand, State, WindModel, EARTH_RADIUS, G0};
 use crate::vehicle::Mission;
 
 // ---------------------------------------------------------------------------
@@ -17,6 +17,21 @@ use crate::vehicle::Mission;
 ///   4. Aerodynamic restoring moment (CP-CG offset)
 ///   5. Aerodynamic damping moment
 pub fn derivatives(state: &State, mission: &Mission, cmd: &GncCommand) -> Deriv {
+    derivatives_inner(state, mission, cmd, state.vel)
+}
+
+/// Same as [`derivatives`] but computes drag and aerodynamic angle-of-attack
+/// from the air-relative velocity `state.vel - wind.velocity_at(altitude)`
+/// instead of the raw inertial velocity, so `wind` actually perturbs the
+/// vehicle rather than being ignored. Ground-relative quantities (`dpos`,
+/// thrust) are untouched.
+pub fn derivatives_with_wind(state: &State, mission: &Mission, cmd: &GncCommand, wind: &WindModel) -> Deriv {
+    let alt = state.pos.z.max(0.0);
+    let aero_vel = state.vel - wind.velocity_at(alt);
+    derivatives_inner(state, mission, cmd, aero_vel)
+}
+
+fn derivatives_inner(state: &State, mission: &Mission, cmd: &GncCommand, aero_vel: Vector3<f64>) -> Deriv {
     let stage = match mission.active_stage(state.stage_idx) {
         Some(s) => s,
         None => return zero_deriv(state),
@@ -29,36 +44,33 @@ pub fn derivatives(state: &State, mission: &Mission, cmd: &GncCommand) -> Deriv
     let remaining_prop = state.mass
         - stage.dry_mass
         - upper_stages_mass(mission, state.stage_idx);
-    let burning = remaining_prop > 0.01 && stage.thrust > 0.0;
+    let stage_t = state.stage_elapsed();
+    let burning = remaining_prop > 0.01 && stage.thrust_at(stage_t) > 0.0;
 
     // --- Gravity (inertial) ---
     let g = G0 * (EARTH_RADIUS / (EARTH_RADIUS + alt)).powi(2);
     let f_gravity = Vector3::new(0.0, 0.0, -g * state.mass);
 
     // --- Thrust (body frame → inertial) ---
+    let throttle = cmd.throttle.clamp(0.0, 1.0);
+    let stage_thrust = stage.thrust_at(stage_t) * throttle;
     let f_thrust_body = if burning {
         // TVC: deflect thrust vector from body +Z by gimbal angles
         let gy = cmd.gimbal_y.clamp(-stage.tvc_max, stage.tvc_max);
         let gz = cmd.gimbal_z.clamp(-stage.tvc_max, stage.tvc_max);
         Vector3::new(
-            stage.thrust * gz.sin(),
-            stage.thrust * gy.sin(),
-            stage.thrust * gy.cos() * gz.cos(),
+            stage_thrust * gz.sin(),
+            stage_thrust * gy.sin(),
+            stage_thrust * gy.cos() * gz.cos(),
         )
     } else {
         Vector3::zeros()
     };
     let f_thrust_inertial = state.quat * f_thrust_body;
 
-    // --- Aerodynamic drag (inertial, opposing velocity) ---
-    let speed = state.vel.norm();
-    let f_drag = if speed > 1e-6 {
-        let q_dyn = 0.5 * atm.density * speed * speed;
-        let drag_mag = q_dyn * stage.cd * stage.area;
-        -state.vel.normalize() * drag_mag
-    } else {
-        Vector3::zeros()
-    };
+    // --- Aerodynamic drag (inertial, opposing air-relative velocity) ---
+    let speed = aero_vel.norm();
+    let f_drag = aerodynamics::drag_force(&aero_vel, &atm, stage.cd, stage.area);
 
     // --- Total force → translational acceleration ---
     let f_total = f_gravity + f_thrust_inertial + f_drag;
@@ -69,32 +81,18 @@ pub fn derivatives(state: &State, mission: &Mission, cmd: &GncCommand) -> Deriv
 
     // TVC torque: thrust offset from CG creates moment
     if burning {
-        // Moment arm from CG to nozzle (body frame, nozzle at -Z)
-        let arm = Vector3::new(0.0, 0.0, -stage.nozzle_offset);
+        // Moment arm from CG to nozzle (body frame, nozzle at -Z).
+        // Thrust-weighted across the engines still firing at stage_t.
+        let arm = Vector3::new(0.0, 0.0, -stage.nozzle_offset_at(stage_t));
         torque_body += arm.cross(&f_thrust_body);
     }
 
     // Aerodynamic restoring moment from CP-CG offset
-    if speed > 1.0 && stage.cp_offset.abs() > 1e-6 {
-        let vel_body = state.quat.inverse() * state.vel;
-        let q_dyn = 0.5 * atm.density * speed * speed;
-        // Normal force coefficient ~ 2.0 for slender body (C_N_alpha)
-        let cn_alpha = 2.0;
-        // Angle of attack components in body frame
-        let alpha_y = vel_body.y.atan2(vel_body.z); // pitch AoA
-        let alpha_z = vel_body.x.atan2(vel_body.z); // yaw AoA
-        let normal_force = q_dyn * stage.area * cn_alpha;
-        // Restoring moment: positive cp_offset = CP ahead of CG = stable
-        torque_body.x += -normal_force * alpha_y * stage.cp_offset;
-        torque_body.y += normal_force * alpha_z * stage.cp_offset;
-    }
+    let vel_body = state.quat.inverse() * aero_vel;
+    torque_body += aerodynamics::restoring_moment(&vel_body, speed, &atm, stage.area, stage.cp_offset);
 
     // Aerodynamic damping (proportional to angular rate)
-    if speed > 1.0 {
-        let q_dyn = 0.5 * atm.density * speed * speed;
-        let damp = q_dyn * stage.area * 0.5; // simplified damping coefficient
-        torque_body -= state.omega * damp;
-    }
+    torque_body += aerodynamics::damping_moment(&state.omega, speed, &atm, stage.area);
 
     // --- Euler's equation: I * domega = torque - omega × (I * omega) ---
     let i_vec = stage.inertia;
@@ -114,7 +112,7 @@ pub fn derivatives(state: &State, mission: &Mission, cmd: &GncCommand) -> Deriv
     let dquat = state.quat.quaternion() * omega_quat * 0.5;
 
     // --- Mass flow ---
-    let dmass = if burning { -stage.mass_flow() } else { 0.0 };
+    let dmass = if burning { -stage.mass_flow_at(stage_t) * throttle } else { 0.0 };
 
     Deriv {
         dpos: state.vel,
@@ -152,7 +150,7 @@ fn zero_deriv(state: &State) -> Deriv {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::vehicle::Stage;
+    use crate::vehicle::{Engine, Stage};
     use nalgebra::UnitQuaternion;
 
     fn test_mission() -> Mission {
@@ -162,12 +160,10 @@ mod tests {
                 name: "S1".into(),
                 dry_mass: 20.0,
                 propellant_mass: 10.0,
-                thrust: 2000.0,
-                isp: 220.0,
+                engines: vec![Engine::new(2000.0, 220.0, 1.0)],
                 cd: 0.3,
                 area: 0.008,
                 inertia: Vector3::new(5.0, 5.0, 0.5),
-                nozzle_offset: 1.0,
                 cp_offset: 0.3,
                 tvc_max: 0.1,
             }],
@@ -183,6 +179,7 @@ mod tests {
             omega: Vector3::zeros(),
             mass: mission.total_mass(),
             stage_idx: 0,
+            stage_ignition_time: 0.0,
         }
     }
 
@@ -201,6 +198,7 @@ mod tests {
         let cmd = GncCommand {
             gimbal_y: 0.05,
             gimbal_z: 0.0,
+            throttle: 1.0,
         };
         let d = derivatives(&s, &m, &cmd);
         assert!(d.domega.x.abs() > 1e-6, "TVC should create pitch torque");
@@ -217,6 +215,7 @@ mod tests {
             omega: Vector3::zeros(),
             mass: m.stages[0].dry_mass,
             stage_idx: 0,
+            stage_ignition_time: 0.0,
         };
         let d = derivatives(&s, &m, &GncCommand::default());
         assert!(d.dvel.z < 0.0, "Only gravity + drag after burnout");
@@ -233,4 +232,34 @@ mod tests {
         .sqrt();
         assert!(dq_norm < 1e-10, "No rotation → zero quat derivative");
     }
+
+    #[test]
+    fn headwind_increases_drag_deceleration() {
+        let m = test_mission();
+        let s = State {
+            time: 50.0,
+            pos: Vector3::new(0.0, 0.0, 2000.0),
+            vel: Vector3::new(0.0, 0.0, 150.0),
+            quat: UnitQuaternion::identity(),
+            omega: Vector3::zeros(),
+            mass: m.stages[0].dry_mass,
+            stage_idx: 0,
+            stage_ignition_time: 0.0,
+        };
+        let no_wind = derivatives_with_wind(&s, &m, &GncCommand::default(), &WindModel::Constant(Vector3::zeros()));
+        // Wind opposing the vehicle's motion raises the air-relative speed
+        // (vel - wind), so drag deceleration grows.
+        let headwind = WindModel::Constant(Vector3::new(0.0, 0.0, -30.0));
+        let with_wind = derivatives_with_wind(&s, &m, &GncCommand::default(), &headwind);
+        assert!(with_wind.dvel.z < no_wind.dvel.z, "higher air-relative speed should mean more drag deceleration");
+    }
+
+    #[test]
+    fn zero_wind_matches_plain_derivatives() {
+        let m = test_mission();
+        let s = pad_state(&m);
+        let a = derivatives(&s, &m, &GncCommand::default());
+        let b = derivatives_with_wind(&s, &m, &GncCommand::default(), &WindModel::default());
+        assert!((a.dvel - b.dvel).norm() < 1e-12);
+    }
 }
@@ -20,6 +20,7 @@ pub struct State {
     pub omega: Vector3<f64>,            // rad/s, body frame angular velocity
     pub mass: f64,                      // kg
     pub stage_idx: usize,               // active stage index
+    pub stage_ignition_time: f64,       // s, mission time at which stage_idx last ignited
 }
 
 impl State {
@@ -34,9 +35,15 @@ impl State {
             omega: self.omega + d.domega * dt,
             mass: (self.mass + d.dmass * dt).max(0.0),
             stage_idx: self.stage_idx,
+            stage_ignition_time: self.stage_ignition_time,
         }
     }
 
+    /// Elapsed time since the active stage ignited (used to age clustered engines).
+    pub fn stage_elapsed(&self) -> f64 {
+        (self.time - self.stage_ignition_time).max(0.0)
+    }
+
     /// Body Z-axis (thrust direction) in inertial frame.
     pub fn body_z(&self) -> Vector3<f64> {
         self.quat * Vector3::z()
@@ -75,10 +82,19 @@ pub struct Deriv {
 // GNC command output
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy)]
 pub struct GncCommand {
-    pub gimbal_y: f64,   // TVC pitch gimbal, rad (positive = nose up)
-    pub gimbal_z: f64,   // TVC yaw gimbal, rad (positive = nose right)
+    pub gimbal_y: f64,  // TVC pitch gimbal, rad (positive = nose up)
+    pub gimbal_z: f64,  // TVC yaw gimbal, rad (positive = nose right)
+    pub throttle: f64,  // [0, 1]; engines scale thrust by this fraction
+}
+
+impl Default for GncCommand {
+    /// Full thrust, zero gimbal — matches the old (throttle-less) behavior
+    /// for controllers that never touch `throttle`.
+    fn default() -> Self {
+        Self { gimbal_y: 0.0, gimbal_z: 0.0, throttle: 1.0 }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -99,3 +115,118 @@ impl Default for SimConfig {
         }
     }
 }
+
+// ---------------------------------------------------------------------------
+// Adaptive-step integrator tolerances
+// ---------------------------------------------------------------------------
+
+/// Tolerances and step bounds for the embedded Dormand-Prince 5(4) integrator
+/// (see [`crate::sim::integrator::rkf45_step`]). Kept separate from
+/// [`SimConfig`] so existing fixed-step call sites are unaffected.
+#[derive(Debug, Clone)]
+pub struct AdaptiveConfig {
+    pub rtol: f64,
+    pub atol: f64,
+    pub dt_min: f64,
+    pub dt_max: f64,
+    pub safety: f64,
+}
+
+impl Default for AdaptiveConfig {
+    fn default() -> Self {
+        Self {
+            rtol: 1e-6,
+            atol: 1e-3,
+            dt_min: 1e-4,
+            dt_max: 1.0,
+            safety: 0.9,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Launch rail & surface wind
+// ---------------------------------------------------------------------------
+
+/// Horizontal wind as a function of altitude, injected into the air-relative
+/// velocity used for drag and angle-of-attack (see
+/// [`crate::dynamics::sixdof::derivatives_with_wind`]).
+#[derive(Debug, Clone)]
+pub enum WindModel {
+    /// Same wind velocity (m/s, inertial ENU) at every altitude.
+    Constant(Vector3<f64>),
+    /// Piecewise-linear interpolation between `(altitude_m, wind_velocity)`
+    /// samples, sorted by altitude. Clamps to the nearest sample outside the
+    /// profiled range.
+    AltitudeProfile(Vec<(f64, Vector3<f64>)>),
+}
+
+impl WindModel {
+    /// Wind velocity (m/s, inertial ENU) at a given geometric altitude.
+    pub fn velocity_at(&self, altitude_m: f64) -> Vector3<f64> {
+        match self {
+            WindModel::Constant(v) => *v,
+            WindModel::AltitudeProfile(samples) => {
+                if samples.is_empty() {
+                    return Vector3::zeros();
+                }
+                if altitude_m <= samples[0].0 {
+                    return samples[0].1;
+                }
+                let last = samples.len() - 1;
+                if altitude_m >= samples[last].0 {
+                    return samples[last].1;
+                }
+                for w in samples.windows(2) {
+                    let (alt0, v0) = w[0];
+                    let (alt1, v1) = w[1];
+                    if altitude_m >= alt0 && altitude_m <= alt1 {
+                        let frac = (altitude_m - alt0) / (alt1 - alt0).max(1e-9);
+                        return v0 + (v1 - v0) * frac;
+                    }
+                }
+                samples[last].1
+            }
+        }
+    }
+}
+
+impl Default for WindModel {
+    fn default() -> Self {
+        WindModel::Constant(Vector3::zeros())
+    }
+}
+
+/// Launch-rail geometry and surface wind. Kept separate from [`SimConfig`]
+/// for the same reason as [`AdaptiveConfig`]: `SimConfig` is built via bare
+/// struct literals at every call site, so adding required fields there would
+/// break them all.
+#[derive(Debug, Clone)]
+pub struct LaunchConfig {
+    pub rail_length: f64, // m; 0 = no rail, free from the pad
+    pub elevation: f64,   // rad above local horizontal, pi/2 = straight up
+    pub azimuth: f64,     // rad, clockwise from north
+    pub wind: WindModel,
+}
+
+impl Default for LaunchConfig {
+    fn default() -> Self {
+        Self {
+            rail_length: 0.0,
+            elevation: std::f64::consts::FRAC_PI_2,
+            azimuth: 0.0,
+            wind: WindModel::default(),
+        }
+    }
+}
+
+/// Initial body→inertial attitude for a launch at `elevation`/`azimuth`: body
+/// +Z (the thrust axis) points along the rail.
+pub fn launch_attitude(elevation: f64, azimuth: f64) -> UnitQuaternion<f64> {
+    let dir = Vector3::new(
+        elevation.cos() * azimuth.sin(),
+        elevation.cos() * azimuth.cos(),
+        elevation.sin(),
+    );
+    UnitQuaternion::rotation_between(&Vector3::z(), &dir).unwrap_or_else(UnitQuaternion::identity)
+}
@@ -0,0 +1,155 @@
+use nalgebra::DVector;
+
+use crate::dynamics::state::{SimConfig, State};
+use crate::sim::runner::simulate_with;
+use crate::vehicle::Mission;
+use super::controller::Controller;
+use super::opti::{levenberg_marquardt, ConvergenceNorm, LmConfig};
+
+// ---------------------------------------------------------------------------
+// Closure-driven trajectory targeting
+// ---------------------------------------------------------------------------
+//
+// Unlike `opti`'s fixed pitch-program/orbital-burn signatures and
+// `targeter`'s fixed enum of design variables/objectives, this targeter takes
+// a caller-supplied `build` closure mapping the free-parameter vector to a
+// `Controller` and caller-supplied `Goal` accessors over the flown
+// trajectory — so it can tune anything from a pitch-kick angle to an
+// arbitrary per-stage gimbal schedule without this module knowing about
+// `PitchProgram`/`TvcController` at all. The LM iteration itself is
+// [`super::opti::levenberg_marquardt`]; this module only builds the
+// tolerance-scaled residual function and the relative finite-difference step
+// on top of it.
+
+/// A free parameter the solver is allowed to tune.
+#[derive(Debug, Clone, Copy)]
+pub struct ClosureVariable {
+    pub initial: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+/// A terminal trajectory quantity, read off the flown trajectory by
+/// `accessor`, driven toward `desired` within `tolerance`.
+pub struct Goal {
+    pub accessor: Box<dyn Fn(&[State]) -> f64>,
+    pub desired: f64,
+    pub tolerance: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SolverResult {
+    pub variables: Vec<f64>,
+    pub residual: Vec<f64>,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// Residual vector, pre-scaled by each goal's tolerance so a single
+/// dimensionless `‖r‖∞ < 1` convergence check works across goals of very
+/// different units (meters, radians, ...).
+fn evaluate(
+    mission: &Mission,
+    config: &SimConfig,
+    goals: &[Goal],
+    build: &dyn Fn(&[f64]) -> Box<dyn Controller>,
+    x: &[f64],
+) -> DVector<f64> {
+    let mut controller = build(x);
+    let (trajectory, _) = simulate_with(mission, config, controller.as_mut());
+    DVector::from_vec(goals.iter().map(|g| ((g.accessor)(&trajectory) - g.desired) / g.tolerance).collect())
+}
+
+/// Tune `vars` to satisfy `goals` via the shared [`levenberg_marquardt`]
+/// core: each variable is forward-differenced with a relative step
+/// `1e-6*|x| + 1e-8` (cheap parameters stay well-conditioned alongside ones
+/// spanning many orders of magnitude), bounds come from each variable's
+/// `min`/`max`, and the dimensionless `tol = 1.0` stop condition works
+/// against `evaluate`'s already-tolerance-scaled residual.
+pub fn solve_closure_target(
+    mission: &Mission,
+    config: &SimConfig,
+    vars: &[ClosureVariable],
+    goals: &[Goal],
+    build: impl Fn(&[f64]) -> Box<dyn Controller>,
+) -> SolverResult {
+    let x0 = DVector::from_vec(vars.iter().map(|v| v.initial).collect());
+    let bounds: Vec<(f64, f64)> = vars.iter().map(|v| (v.min, v.max)).collect();
+
+    let lm_config = LmConfig {
+        tol: 1.0,
+        max_iters: 30,
+        finite_diff_step: 0.0,
+        lambda0: 1e-2,
+        lambda_up: 10.0,
+        lambda_down: 0.3,
+        convergence: ConvergenceNorm::LInf,
+    };
+
+    let report = levenberg_marquardt(
+        x0,
+        Some(&bounds),
+        lm_config,
+        |x, j| 1e-6 * x[j].abs() + 1e-8,
+        |x| evaluate(mission, config, goals, &build, x.as_slice()),
+    );
+
+    SolverResult {
+        variables: report.x,
+        residual: report.residual,
+        iterations: report.iterations,
+        converged: report.converged,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gnc::{PitchProgram, TvcController};
+    use crate::vehicle::presets;
+
+    fn apogee(trajectory: &[State]) -> f64 {
+        trajectory.iter().map(|s| s.pos.z).fold(f64::MIN, f64::max)
+    }
+
+    #[test]
+    fn tunes_pitchover_end_time_to_hit_apogee() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 120.0 };
+
+        let build = |x: &[f64]| -> Box<dyn Controller> {
+            let program = PitchProgram { t_pitchover_end: x[0], ..PitchProgram::default() };
+            Box::new(TvcController::with_program(program))
+        };
+
+        let baseline = {
+            let mut controller = build(&[15.0]);
+            let (trajectory, _) = simulate_with(&mission, &sim_config, controller.as_mut());
+            apogee(&trajectory)
+        };
+        let target_apogee = baseline * 0.8;
+
+        let vars = vec![ClosureVariable { initial: 15.0, min: 1.0, max: 60.0 }];
+        let goals = vec![Goal { accessor: Box::new(apogee), desired: target_apogee, tolerance: 50.0 }];
+
+        let result = solve_closure_target(&mission, &sim_config, &vars, &goals, build);
+        assert!(result.residual[0].abs() < (baseline - target_apogee).abs() / 50.0);
+    }
+
+    #[test]
+    fn variable_stays_within_bounds() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 60.0 };
+
+        let build = |x: &[f64]| -> Box<dyn Controller> {
+            let program = PitchProgram { target_pitch: x[0], ..PitchProgram::default() };
+            Box::new(TvcController::with_program(program))
+        };
+
+        let vars = vec![ClosureVariable { initial: 45.0_f64.to_radians(), min: 0.0, max: 30.0_f64.to_radians() }];
+        let goals = vec![Goal { accessor: Box::new(apogee), desired: 1e9, tolerance: 1.0 }];
+
+        let result = solve_closure_target(&mission, &sim_config, &vars, &goals, build);
+        assert!(result.variables[0] >= 0.0 && result.variables[0] <= 30.0_f64.to_radians() + 1e-9);
+    }
+}
@@ -0,0 +1,270 @@
+use crate::dynamics::state::{GncCommand, SimConfig, State};
+use crate::rng::Rng;
+use crate::sim::runner::simulate_with;
+use crate::vehicle::Mission;
+use super::controller::Controller;
+use super::ga_core::{ga_search, GaSearchConfig, Genome};
+
+// ---------------------------------------------------------------------------
+// Genetic-algorithm ascent guidance optimizer (interpolated gimbal schedule)
+// ---------------------------------------------------------------------------
+//
+// Alongside `optimize` (fixed 3-knot PitchProgram genome) and `ga` (raw
+// per-timestep held-constant gimbal/throttle genome for descent): here the
+// genome is a fixed-length sequence of (pitch, yaw) gimbal commands sampled
+// at evenly spaced time nodes and *linearly interpolated* between them,
+// decoded into an `InterpolatedGimbalController` and flown through
+// `sim::simulate_with`. Single-point crossover (rather than the other two's
+// uniform/arithmetic blend) plus Gaussian mutation is a good fit for a
+// smoothly-varying open-loop schedule like this. Useful where the dynamics
+// are too nonsmooth around staging for the LM targeters' finite-difference
+// Jacobians to behave. The generation loop itself is
+// [`super::ga_core::ga_search`]; this module only implements [`Genome`] for
+// the gimbal-node genome and decodes the winner into an
+// [`InterpolatedGimbalController`].
+
+/// One (pitch, yaw) gimbal command node in the evolved schedule, rad.
+#[derive(Debug, Clone, Copy)]
+pub struct GimbalNode {
+    pub pitch: f64,
+    pub yaw: f64,
+}
+
+/// Decodes a [`GimbalNode`] schedule into gimbal commands by linearly
+/// interpolating between the two nodes bracketing the current time.
+#[derive(Debug, Clone)]
+pub struct InterpolatedGimbalController {
+    pub genome: Vec<GimbalNode>,
+    pub node_dt: f64,
+}
+
+impl Controller for InterpolatedGimbalController {
+    fn control(&mut self, state: &State, _mission: &Mission, _dt: f64) -> GncCommand {
+        if self.genome.is_empty() || self.node_dt <= 0.0 {
+            return GncCommand::default();
+        }
+        let n = self.genome.len();
+        let t = state.time / self.node_dt;
+        let i0 = (t.floor() as usize).min(n - 1);
+        let i1 = (i0 + 1).min(n - 1);
+        let frac = (t - i0 as f64).clamp(0.0, 1.0);
+
+        let a = self.genome[i0];
+        let b = self.genome[i1];
+        GncCommand {
+            gimbal_y: a.pitch + (b.pitch - a.pitch) * frac,
+            gimbal_z: a.yaw + (b.yaw - a.yaw) * frac,
+            throttle: 1.0,
+        }
+    }
+
+    fn name(&self) -> &str {
+        "InterpolatedGimbalController"
+    }
+}
+
+/// What the search is trying to achieve.
+#[derive(Debug, Clone, Copy)]
+pub enum GuidanceObjective {
+    /// Fly as high as possible.
+    MaxApogee,
+    /// Hit a specific downrange distance and altitude at apogee.
+    MinMissDistance { target_downrange_m: f64, target_altitude_m: f64 },
+}
+
+/// Tunable knobs of the search.
+#[derive(Debug, Clone, Copy)]
+pub struct EvolveConfig {
+    pub nodes: usize,
+    pub node_dt: f64,
+    pub population: usize,
+    pub generations: usize,
+    pub elite_count: usize,
+    pub tournament_size: usize,
+    pub mutation_rate: f64,
+    pub mutation_std_frac: f64, // mutation std as a fraction of the gimbal bound
+    pub seed: u64,
+}
+
+impl Default for EvolveConfig {
+    fn default() -> Self {
+        Self {
+            nodes: 8,
+            node_dt: 5.0,
+            population: 40,
+            generations: 30,
+            elite_count: 2,
+            tournament_size: 3,
+            mutation_rate: 0.2,
+            mutation_std_frac: 0.15,
+            seed: 0,
+        }
+    }
+}
+
+/// Best schedule found, its decoded controller, fitness, and trajectory.
+#[derive(Debug, Clone)]
+pub struct EvolveResult {
+    pub controller: InterpolatedGimbalController,
+    pub fitness: f64,
+    pub trajectory: Vec<State>,
+}
+
+/// Conservative gimbal bound shared by every node: the smallest `tvc_max`
+/// across stages, since a node's time doesn't map to a specific stage until
+/// the genome is actually flown.
+fn gimbal_bound(mission: &Mission) -> f64 {
+    mission
+        .stages
+        .iter()
+        .map(|s| s.tvc_max)
+        .fold(f64::MAX, f64::min)
+        .max(1e-3)
+}
+
+fn random_genome(rng: &mut Rng, nodes: usize, bound: f64) -> Vec<GimbalNode> {
+    (0..nodes)
+        .map(|_| GimbalNode { pitch: rng.uniform(-bound, bound), yaw: rng.uniform(-bound, bound) })
+        .collect()
+}
+
+fn repair(genome: &mut [GimbalNode], bound: f64) {
+    for node in genome.iter_mut() {
+        node.pitch = node.pitch.clamp(-bound, bound);
+        node.yaw = node.yaw.clamp(-bound, bound);
+    }
+}
+
+fn fitness_of(genome: &[GimbalNode], config: &EvolveConfig, mission: &Mission, sim_config: &SimConfig, objective: GuidanceObjective) -> f64 {
+    let mut controller = InterpolatedGimbalController { genome: genome.to_vec(), node_dt: config.node_dt };
+    let (trajectory, _) = simulate_with(mission, sim_config, &mut controller);
+
+    let apogee_state = trajectory.iter().max_by(|a, b| a.pos.z.partial_cmp(&b.pos.z).unwrap()).unwrap();
+    if !apogee_state.pos.z.is_finite() || apogee_state.pos.z < 1.0 {
+        return -1e9;
+    }
+
+    match objective {
+        GuidanceObjective::MaxApogee => apogee_state.pos.z,
+        GuidanceObjective::MinMissDistance { target_downrange_m, target_altitude_m } => {
+            let downrange = (apogee_state.pos.x.powi(2) + apogee_state.pos.y.powi(2)).sqrt();
+            -((apogee_state.pos.z - target_altitude_m).abs()) - (downrange - target_downrange_m).abs()
+        }
+    }
+}
+
+/// Single-point crossover: take `a`'s nodes up to a random cut, `b`'s after it.
+fn crossover(rng: &mut Rng, a: &[GimbalNode], b: &[GimbalNode]) -> Vec<GimbalNode> {
+    let cut = rng.index(a.len());
+    a[..cut].iter().chain(b[cut..].iter()).copied().collect()
+}
+
+fn mutate(rng: &mut Rng, genome: &mut [GimbalNode], bound: f64, mutation_rate: f64, mutation_std_frac: f64) {
+    let std = bound * mutation_std_frac;
+    for node in genome.iter_mut() {
+        if rng.next_f64() < mutation_rate {
+            node.pitch = rng.gauss(node.pitch, std);
+        }
+        if rng.next_f64() < mutation_rate {
+            node.yaw = rng.gauss(node.yaw, std);
+        }
+    }
+}
+
+/// Bundles the shared gimbal bound with the fixed node count, since
+/// `Genome::Bounds` has no other way to carry the length the engine needs to
+/// build a random genome.
+#[derive(Debug, Clone, Copy)]
+struct GimbalBounds {
+    bound: f64,
+    nodes: usize,
+}
+
+impl Genome for Vec<GimbalNode> {
+    type Bounds = GimbalBounds;
+
+    fn random(rng: &mut Rng, bounds: &GimbalBounds) -> Self {
+        random_genome(rng, bounds.nodes, bounds.bound)
+    }
+
+    fn crossover(rng: &mut Rng, a: &Self, b: &Self) -> Self {
+        crossover(rng, a, b)
+    }
+
+    fn mutate(rng: &mut Rng, genome: &mut Self, bounds: &GimbalBounds, mutation_rate: f64, mutation_std_frac: f64) {
+        mutate(rng, genome, bounds.bound, mutation_rate, mutation_std_frac)
+    }
+
+    fn repair(genome: &mut Self, bounds: &GimbalBounds) {
+        repair(genome, bounds.bound)
+    }
+}
+
+/// Evolve an interpolated (pitch, yaw) gimbal schedule for `mission` against
+/// `objective`, seeded for reproducibility.
+pub fn evolve_guidance(mission: &Mission, sim_config: &SimConfig, objective: GuidanceObjective, config: EvolveConfig) -> EvolveResult {
+    let bound = gimbal_bound(mission);
+    let bounds = GimbalBounds { bound, nodes: config.nodes };
+
+    let search_config = GaSearchConfig {
+        population: config.population,
+        generations: config.generations,
+        elite_count: config.elite_count,
+        tournament_size: config.tournament_size,
+        mutation_rate: config.mutation_rate,
+        mutation_std_frac: config.mutation_std_frac,
+        stall_generations: None,
+        seed: config.seed,
+    };
+
+    let (best_genome, best_fitness) =
+        ga_search(&bounds, &search_config, |g| fitness_of(g, &config, mission, sim_config, objective));
+
+    let controller = InterpolatedGimbalController { genome: best_genome, node_dt: config.node_dt };
+    let mut flying = controller.clone();
+    let (trajectory, _) = simulate_with(mission, sim_config, &mut flying);
+
+    EvolveResult { controller, fitness: best_fitness, trajectory }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vehicle::presets;
+
+    #[test]
+    fn genome_length_matches_config() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 60.0 };
+        let config = EvolveConfig { population: 8, generations: 3, nodes: 5, ..EvolveConfig::default() };
+        let result = evolve_guidance(&mission, &sim_config, GuidanceObjective::MaxApogee, config);
+        assert_eq!(result.controller.genome.len(), 5);
+    }
+
+    #[test]
+    fn same_seed_reproduces_result() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 60.0 };
+        let config = EvolveConfig { population: 8, generations: 3, ..EvolveConfig::default() };
+
+        let a = evolve_guidance(&mission, &sim_config, GuidanceObjective::MaxApogee, config);
+        let b = evolve_guidance(&mission, &sim_config, GuidanceObjective::MaxApogee, config);
+
+        assert!((a.fitness - b.fitness).abs() < 1e-9);
+        assert!((a.controller.genome[0].pitch - b.controller.genome[0].pitch).abs() < 1e-9);
+    }
+
+    #[test]
+    fn evolved_schedule_respects_gimbal_bound() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 60.0 };
+        let config = EvolveConfig { population: 8, generations: 3, ..EvolveConfig::default() };
+        let bound = gimbal_bound(&mission);
+
+        let result = evolve_guidance(&mission, &sim_config, GuidanceObjective::MaxApogee, config);
+        for node in &result.controller.genome {
+            assert!(node.pitch >= -bound - 1e-9 && node.pitch <= bound + 1e-9);
+            assert!(node.yaw >= -bound - 1e-9 && node.yaw <= bound + 1e-9);
+        }
+    }
+}
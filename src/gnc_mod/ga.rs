@@ -0,0 +1,280 @@
+use crate::dynamics::state::{GncCommand, SimConfig, State};
+use crate::rng::Rng;
+use crate::sim::runner::simulate_with;
+use crate::vehicle::Mission;
+use super::ga_core::{ga_search, GaSearchConfig, Genome};
+use super::Controller;
+
+// ---------------------------------------------------------------------------
+// Genetic-algorithm optimizer over a raw command-sequence genome
+// ---------------------------------------------------------------------------
+//
+// Unlike `optimize::optimize_ascent` (which searches the smooth, 3-knot
+// `PitchProgram`), this searches a flat sequence of (gimbal, throttle)
+// commands sampled every `gene_dt` seconds and held constant in between —
+// suited to non-smooth objectives like powered descent, where the optimal
+// policy isn't expressible as a few analytic knots. The generation loop
+// itself is [`super::ga_core::ga_search`]; this module only implements
+// [`Genome`] for the command-sequence genome (bundling the bounds with a
+// fixed `len` the engine doesn't otherwise know about) and decodes the
+// winner into a [`ScriptedController`].
+
+/// One gene: a held (gimbal_y, throttle) command.
+#[derive(Debug, Clone, Copy)]
+pub struct CommandGene {
+    pub gimbal_y: f64,
+    pub throttle: f64,
+}
+
+/// Inclusive search bounds for every gene.
+#[derive(Debug, Clone, Copy)]
+pub struct Bounds {
+    pub gimbal_y: (f64, f64),
+    pub throttle: (f64, f64),
+}
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Self {
+            gimbal_y: (-0.1, 0.1),
+            throttle: (0.0, 1.0),
+        }
+    }
+}
+
+impl Bounds {
+    fn clamp(&self, gene: &mut CommandGene) {
+        gene.gimbal_y = gene.gimbal_y.clamp(self.gimbal_y.0, self.gimbal_y.1);
+        gene.throttle = gene.throttle.clamp(self.throttle.0, self.throttle.1);
+    }
+
+    fn random_gene(&self, rng: &mut Rng) -> CommandGene {
+        CommandGene {
+            gimbal_y: rng.uniform(self.gimbal_y.0, self.gimbal_y.1),
+            throttle: rng.uniform(self.throttle.0, self.throttle.1),
+        }
+    }
+}
+
+/// Weighted scoring of a landing: rewards a soft, on-target, fuel-conserving
+/// touchdown.
+#[derive(Debug, Clone, Copy)]
+pub struct LandingObjective {
+    pub offset_weight: f64,
+    pub fuel_reward: f64,
+}
+
+impl Default for LandingObjective {
+    fn default() -> Self {
+        Self { offset_weight: 1.0, fuel_reward: 1.0 }
+    }
+}
+
+/// Tunable knobs of the search itself.
+#[derive(Debug, Clone, Copy)]
+pub struct GaConfig {
+    pub population: usize,
+    pub generations: usize,
+    pub elite_count: usize,
+    pub tournament_size: usize,
+    pub mutation_rate: f64,
+    pub mutation_std_frac: f64,
+    pub stall_generations: usize,
+    pub seed: u64,
+}
+
+impl Default for GaConfig {
+    fn default() -> Self {
+        Self {
+            population: 40,
+            generations: 30,
+            elite_count: 2,
+            tournament_size: 3,
+            mutation_rate: 0.15,
+            mutation_std_frac: 0.1,
+            stall_generations: 10,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GaResult {
+    pub genome: Vec<CommandGene>,
+    pub fitness: f64,
+    pub trajectory: Vec<State>,
+}
+
+/// Plays back a fixed genome, holding each gene for `gene_dt` seconds.
+struct ScriptedController<'a> {
+    genome: &'a [CommandGene],
+    gene_dt: f64,
+}
+
+impl<'a> Controller for ScriptedController<'a> {
+    fn control(&mut self, state: &State, _mission: &Mission, _dt: f64) -> GncCommand {
+        let idx = ((state.time / self.gene_dt) as usize).min(self.genome.len().saturating_sub(1));
+        let gene = self.genome.get(idx).copied().unwrap_or(CommandGene { gimbal_y: 0.0, throttle: 0.0 });
+        GncCommand { gimbal_y: gene.gimbal_y, gimbal_z: 0.0, throttle: gene.throttle }
+    }
+
+    fn name(&self) -> &str {
+        "Scripted"
+    }
+}
+
+fn genome_len(sim_config: &SimConfig, gene_dt: f64) -> usize {
+    ((sim_config.max_time / gene_dt).ceil() as usize).max(1)
+}
+
+fn random_genome(rng: &mut Rng, bounds: &Bounds, len: usize) -> Vec<CommandGene> {
+    (0..len).map(|_| bounds.random_gene(rng)).collect()
+}
+
+/// Fly one genome and score its landing against `objective`. Genomes that
+/// never leave the pad score the worst, so the search doesn't get stuck
+/// rewarding "don't launch" as a local optimum.
+fn fitness_of(
+    genome: &[CommandGene],
+    mission: &Mission,
+    sim_config: &SimConfig,
+    gene_dt: f64,
+    objective: LandingObjective,
+) -> f64 {
+    let mut controller = ScriptedController { genome, gene_dt };
+    let (trajectory, _) = simulate_with(mission, sim_config, &mut controller);
+
+    let apogee = trajectory.iter().map(|s| s.pos.z).fold(0.0_f64, f64::max);
+    if apogee < 1.0 {
+        return -1e9;
+    }
+
+    let landing = trajectory.last().unwrap();
+    let landing_speed = landing.vel.norm();
+    let landing_offset = (landing.pos.x.powi(2) + landing.pos.y.powi(2)).sqrt();
+    let fuel_remaining = landing.mass - mission.stages.last().map(|s| s.dry_mass).unwrap_or(0.0);
+
+    -landing_speed - objective.offset_weight * landing_offset + objective.fuel_reward * fuel_remaining.max(0.0)
+}
+
+fn crossover(rng: &mut Rng, a: &[CommandGene], b: &[CommandGene]) -> Vec<CommandGene> {
+    a.iter()
+        .zip(b.iter())
+        .map(|(ga, gb)| if rng.next_f64() < 0.5 { *ga } else { *gb })
+        .collect()
+}
+
+fn mutate(rng: &mut Rng, genome: &mut [CommandGene], bounds: &Bounds, mutation_rate: f64, mutation_std_frac: f64) {
+    let gy_std = (bounds.gimbal_y.1 - bounds.gimbal_y.0) * mutation_std_frac;
+    let th_std = (bounds.throttle.1 - bounds.throttle.0) * mutation_std_frac;
+    for gene in genome.iter_mut() {
+        if rng.next_f64() < mutation_rate {
+            gene.gimbal_y = rng.gauss(gene.gimbal_y, gy_std);
+        }
+        if rng.next_f64() < mutation_rate {
+            gene.throttle = rng.gauss(gene.throttle, th_std);
+        }
+        bounds.clamp(gene);
+    }
+}
+
+/// Bundles the per-gene [`Bounds`] with the fixed genome length, since
+/// `Genome::Bounds` has no other way to carry the length the engine needs to
+/// build a random genome.
+#[derive(Debug, Clone, Copy)]
+struct CommandBounds {
+    bounds: Bounds,
+    len: usize,
+}
+
+impl Genome for Vec<CommandGene> {
+    type Bounds = CommandBounds;
+
+    fn random(rng: &mut Rng, bounds: &CommandBounds) -> Self {
+        random_genome(rng, &bounds.bounds, bounds.len)
+    }
+
+    fn crossover(rng: &mut Rng, a: &Self, b: &Self) -> Self {
+        crossover(rng, a, b)
+    }
+
+    fn mutate(rng: &mut Rng, genome: &mut Self, bounds: &CommandBounds, mutation_rate: f64, mutation_std_frac: f64) {
+        mutate(rng, genome, &bounds.bounds, mutation_rate, mutation_std_frac)
+    }
+
+    fn repair(_genome: &mut Self, _bounds: &CommandBounds) {
+        // `mutate` above already clamps every gene in place, mutated or not.
+    }
+}
+
+/// Search a raw (gimbal, throttle)-per-timestep command sequence for
+/// `mission` against `objective`, sampled every `gene_dt` seconds.
+pub fn optimize_descent(
+    mission: &Mission,
+    sim_config: &SimConfig,
+    gene_dt: f64,
+    objective: LandingObjective,
+    bounds: Bounds,
+    ga_config: GaConfig,
+) -> GaResult {
+    let len = genome_len(sim_config, gene_dt);
+    let command_bounds = CommandBounds { bounds, len };
+
+    let search_config = GaSearchConfig {
+        population: ga_config.population,
+        generations: ga_config.generations,
+        elite_count: ga_config.elite_count,
+        tournament_size: ga_config.tournament_size,
+        mutation_rate: ga_config.mutation_rate,
+        mutation_std_frac: ga_config.mutation_std_frac,
+        stall_generations: Some(ga_config.stall_generations),
+        seed: ga_config.seed,
+    };
+
+    let (best_genome, best_fitness) = ga_search(&command_bounds, &search_config, |g| {
+        fitness_of(g, mission, sim_config, gene_dt, objective)
+    });
+
+    let mut controller = ScriptedController { genome: &best_genome, gene_dt };
+    let (trajectory, _) = simulate_with(mission, sim_config, &mut controller);
+
+    GaResult { genome: best_genome, fitness: best_fitness, trajectory }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vehicle::presets;
+
+    #[test]
+    fn genome_len_covers_full_duration() {
+        let sim_config = SimConfig { dt: 0.02, max_time: 10.0 };
+        assert_eq!(genome_len(&sim_config, 2.0), 5);
+    }
+
+    #[test]
+    fn same_seed_reproduces_result() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 30.0 };
+        let ga_config = GaConfig { population: 8, generations: 3, ..GaConfig::default() };
+
+        let a = optimize_descent(&mission, &sim_config, 2.0, LandingObjective::default(), Bounds::default(), ga_config);
+        let b = optimize_descent(&mission, &sim_config, 2.0, LandingObjective::default(), Bounds::default(), ga_config);
+
+        assert!((a.fitness - b.fitness).abs() < 1e-9);
+    }
+
+    #[test]
+    fn optimized_genome_respects_bounds() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 30.0 };
+        let bounds = Bounds::default();
+        let ga_config = GaConfig { population: 8, generations: 3, ..GaConfig::default() };
+
+        let result = optimize_descent(&mission, &sim_config, 2.0, LandingObjective::default(), bounds, ga_config);
+        for gene in &result.genome {
+            assert!(gene.gimbal_y >= bounds.gimbal_y.0 && gene.gimbal_y <= bounds.gimbal_y.1);
+            assert!(gene.throttle >= bounds.throttle.0 && gene.throttle <= bounds.throttle.1);
+        }
+    }
+}
@@ -7,17 +7,41 @@ use crate::vehicle::Mission;
 // Guidance: desired pitch angle as a function of time/state
 // ---------------------------------------------------------------------------
 
+/// Tunable knots of the ascent pitch program.
+///
+/// This is the parameterization the genetic-algorithm and differential
+/// correction optimizers in [`crate::gnc::optimize`] and [`crate::gnc::target`]
+/// search over.
+#[derive(Debug, Clone, Copy)]
+pub struct PitchProgram {
+    pub t_vertical: f64,       // seconds of vertical ascent
+    pub t_pitchover_end: f64,  // end of pitchover maneuver
+    pub target_pitch: f64,    // target pitch (rad) at end of pitchover
+}
+
+impl Default for PitchProgram {
+    fn default() -> Self {
+        Self {
+            t_vertical: 2.0,
+            t_pitchover_end: 15.0,
+            target_pitch: 45.0_f64.to_radians(),
+        }
+    }
+}
+
 /// Pitch program: returns desired pitch angle (rad from horizontal).
 /// - Phase 1 (0 to t_vertical): vertical ascent (90 deg)
 /// - Phase 2 (t_vertical to t_pitchover_end): linear pitchover
 /// - Phase 3 (after pitchover): gravity turn (follow velocity)
 pub fn guidance_pitch(state: &State, mission: &Mission) -> f64 {
     let _ = mission; // available for future per-mission tuning
-    let t = state.time;
+    guidance_pitch_with(&PitchProgram::default(), state)
+}
 
-    let t_vertical = 2.0;       // seconds of vertical ascent
-    let t_pitchover_end = 15.0; // end of pitchover maneuver
-    let target_pitch = 45.0_f64.to_radians(); // target pitch at end of pitchover
+/// Same as [`guidance_pitch`] but driven by an explicit, tunable [`PitchProgram`].
+pub fn guidance_pitch_with(program: &PitchProgram, state: &State) -> f64 {
+    let t = state.time;
+    let PitchProgram { t_vertical, t_pitchover_end, target_pitch } = *program;
 
     if t < t_vertical {
         // Vertical ascent
@@ -52,6 +76,7 @@ mod tests {
             omega: Vector3::zeros(),
             mass: 30.0,
             stage_idx: 0,
+            stage_ignition_time: 0.0,
         };
         let mission = Mission {
             name: "T".into(),
@@ -71,6 +96,7 @@ mod tests {
             omega: Vector3::zeros(),
             mass: 25.0,
             stage_idx: 0,
+            stage_ignition_time: 0.0,
         };
         let mission = Mission {
             name: "T".into(),
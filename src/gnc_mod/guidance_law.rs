@@ -0,0 +1,179 @@
+use nalgebra::Vector3;
+
+use crate::dynamics::state::State;
+use super::guidance::{guidance_pitch_with, PitchProgram};
+
+// ---------------------------------------------------------------------------
+// Pluggable guidance laws
+// ---------------------------------------------------------------------------
+//
+// A `GuidanceLaw` decides where to point the vehicle and how hard to burn;
+// `TvcController` only knows how to chase whatever direction/throttle it's
+// handed. This lets guidance strategies be composed and swapped without
+// touching the PID/TVC plumbing.
+
+pub trait GuidanceLaw {
+    /// Desired unit thrust direction, inertial frame.
+    fn direction(&self, state: &State) -> Vector3<f64>;
+    /// Desired throttle fraction, [0, 1].
+    fn throttle(&self, state: &State) -> f64;
+    /// Whether this law considers its objective achieved (e.g. a burn target
+    /// reached). Laws that run for the whole flight can always return `false`.
+    fn achieved(&self, state: &State) -> bool;
+}
+
+/// The existing vertical/pitchover/gravity-turn ascent program, expressed as
+/// a direction vector rather than a bare pitch angle so it composes with
+/// [`GuidanceLaw`]. No lateral steering: the direction always stays in the
+/// local vertical plane.
+impl GuidanceLaw for PitchProgram {
+    fn direction(&self, state: &State) -> Vector3<f64> {
+        let pitch = guidance_pitch_with(self, state);
+        Vector3::new(0.0, pitch.cos(), pitch.sin())
+    }
+
+    fn throttle(&self, _state: &State) -> f64 {
+        1.0
+    }
+
+    fn achieved(&self, _state: &State) -> bool {
+        false
+    }
+}
+
+/// Hold a fixed inertial direction regardless of state.
+#[derive(Debug, Clone, Copy)]
+pub struct ConstantDirection {
+    direction: Vector3<f64>,
+}
+
+impl ConstantDirection {
+    pub fn new(direction: Vector3<f64>) -> Self {
+        Self { direction: direction.normalize() }
+    }
+}
+
+impl GuidanceLaw for ConstantDirection {
+    fn direction(&self, _state: &State) -> Vector3<f64> {
+        self.direction
+    }
+
+    fn throttle(&self, _state: &State) -> f64 {
+        1.0
+    }
+
+    fn achieved(&self, _state: &State) -> bool {
+        false
+    }
+}
+
+/// Point along the velocity vector (zero angle of attack), the classic
+/// gravity-turn strategy. Falls back to straight up while nearly at rest.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GravityTurn;
+
+impl GuidanceLaw for GravityTurn {
+    fn direction(&self, state: &State) -> Vector3<f64> {
+        let speed = state.vel.norm();
+        if speed > 5.0 {
+            state.vel / speed
+        } else {
+            Vector3::new(0.0, 0.0, 1.0)
+        }
+    }
+
+    fn throttle(&self, _state: &State) -> f64 {
+        1.0
+    }
+
+    fn achieved(&self, _state: &State) -> bool {
+        false
+    }
+}
+
+/// Coast at zero throttle until `trigger` fires, then burn at full throttle
+/// along a fixed direction. Useful for a circularization or deorbit burn
+/// that should wait for an apogee/perigee condition.
+pub struct CoastThenBurn {
+    direction: Vector3<f64>,
+    trigger: Box<dyn Fn(&State) -> bool>,
+}
+
+impl CoastThenBurn {
+    pub fn new(direction: Vector3<f64>, trigger: Box<dyn Fn(&State) -> bool>) -> Self {
+        Self { direction: direction.normalize(), trigger }
+    }
+}
+
+impl GuidanceLaw for CoastThenBurn {
+    fn direction(&self, _state: &State) -> Vector3<f64> {
+        self.direction
+    }
+
+    fn throttle(&self, state: &State) -> f64 {
+        if (self.trigger)(state) { 1.0 } else { 0.0 }
+    }
+
+    fn achieved(&self, state: &State) -> bool {
+        (self.trigger)(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_at(time: f64, vel: Vector3<f64>) -> State {
+        State {
+            time,
+            pos: Vector3::zeros(),
+            vel,
+            quat: nalgebra::UnitQuaternion::identity(),
+            omega: Vector3::zeros(),
+            mass: 20.0,
+            stage_idx: 0,
+            stage_ignition_time: 0.0,
+        }
+    }
+
+    #[test]
+    fn constant_direction_ignores_state() {
+        let law = ConstantDirection::new(Vector3::new(1.0, 2.0, 3.0));
+        let a = law.direction(&state_at(0.0, Vector3::zeros()));
+        let b = law.direction(&state_at(50.0, Vector3::new(10.0, 0.0, 0.0)));
+        assert_eq!(a, b);
+        assert!((a.norm() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn gravity_turn_follows_velocity() {
+        let law = GravityTurn;
+        let dir = law.direction(&state_at(10.0, Vector3::new(0.0, 0.0, 100.0)));
+        assert!((dir - Vector3::new(0.0, 0.0, 1.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn gravity_turn_defaults_vertical_at_rest() {
+        let law = GravityTurn;
+        let dir = law.direction(&state_at(0.0, Vector3::zeros()));
+        assert!((dir.z - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn coast_then_burn_waits_for_trigger() {
+        let law = CoastThenBurn::new(Vector3::new(0.0, 1.0, 0.0), Box::new(|s: &State| s.time > 100.0));
+        assert_eq!(law.throttle(&state_at(50.0, Vector3::zeros())), 0.0);
+        assert_eq!(law.throttle(&state_at(150.0, Vector3::zeros())), 1.0);
+        assert!(law.achieved(&state_at(150.0, Vector3::zeros())));
+        assert!(!law.achieved(&state_at(50.0, Vector3::zeros())));
+    }
+
+    #[test]
+    fn pitch_program_direction_matches_guidance_pitch() {
+        let program = PitchProgram::default();
+        let s = state_at(0.0, Vector3::zeros());
+        let dir = program.direction(&s);
+        let pitch = guidance_pitch_with(&program, &s);
+        assert!((dir.z - pitch.sin()).abs() < 1e-9);
+    }
+}
@@ -1,9 +1,28 @@
 pub mod controller;
 pub mod pid;
 pub mod guidance;
+pub mod guidance_law;
 pub mod tvc;
+mod ga_core;
+pub mod optimize;
+pub mod target;
+pub mod opti;
+pub mod ga;
+pub mod sensors;
+pub mod targeter;
+pub mod evolve;
+pub mod closure_target;
 
 pub use controller::Controller;
 pub use pid::Pid;
-pub use guidance::guidance_pitch;
+pub use guidance::{guidance_pitch, guidance_pitch_with, PitchProgram};
+pub use guidance_law::{CoastThenBurn, ConstantDirection, GravityTurn, GuidanceLaw};
 pub use tvc::{TvcController, GncSystem};
+pub use optimize::{optimize_ascent, Bounds, GaConfig, Objective, OptimizeResult};
+pub use target::{solve_to_target, Controls, TargetConfig, Targets, TargetResult};
+pub use opti::{solve_ascent_lm, solve_orbital_burn_lm, LmConfig, LmReport};
+pub use ga::{optimize_descent, CommandGene, GaResult, LandingObjective};
+pub use sensors::{ImuModel, NoiseConfig, NoisyController};
+pub use targeter::{solve_targets, ObjectiveParam, TargetObjective, TargeterConfig, TargeterResult, Variable, VariableKind};
+pub use evolve::{evolve_guidance, EvolveConfig, EvolveResult, GimbalNode, GuidanceObjective, InterpolatedGimbalController};
+pub use closure_target::{solve_closure_target, ClosureVariable, Goal, SolverResult};
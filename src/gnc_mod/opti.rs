@@ -0,0 +1,377 @@
+use nalgebra::{DMatrix, DVector};
+
+use crate::dynamics::state::SimConfig;
+use crate::orbital::{propagate_orbit, OrbitalState};
+use crate::sim::runner::simulate_with;
+use crate::vehicle::Mission;
+use super::guidance::PitchProgram;
+use super::target::Targets;
+use super::tvc::TvcController;
+
+// ---------------------------------------------------------------------------
+// Levenberg-Marquardt trajectory targeting
+// ---------------------------------------------------------------------------
+//
+// `levenberg_marquardt` below is the one shared damped-least-squares core for
+// every "tune x to drive residual(x) to zero" problem in this crate: the
+// launch pitch program and orbital burn targeting in this module,
+// [`super::targeter::solve_targets`]'s named design-variable/objective
+// targeting, and [`super::closure_target::solve_closure_target`]'s
+// closure-driven trajectory targeting. Each call site supplies its own
+// residual function, per-variable finite-difference step (fixed, or a
+// function of the current `x` for relative stepping), and optional
+// per-variable bounds; the normal
+// equations `(JᵀJ + λ·diag(JᵀJ)) δx = -Jᵀr`, the λ grow/shrink schedule, and
+// the bounds-clamped accept/reject loop live here exactly once.
+
+/// Which norm [`levenberg_marquardt`] uses for its convergence check (the
+/// damped-step accept/reject decision always uses L2, matching every
+/// pre-consolidation solver this core replaced).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConvergenceNorm {
+    /// `‖r‖₂ < tol`. Right for raw-unit residuals, as in this module's own
+    /// [`solve_ascent_lm`]/[`solve_orbital_burn_lm`].
+    #[default]
+    L2,
+    /// `‖r‖∞ < tol`. Right for callers that pre-scale every residual by its
+    /// own tolerance so a single dimensionless `tol = 1.0` covers objectives
+    /// of different units and magnitudes, as in
+    /// [`super::targeter::solve_targets`] and
+    /// [`super::closure_target::solve_closure_target`]: an L2 norm over N
+    /// such residuals can clear `tol` while one objective is still far out
+    /// of tolerance, or miss it when several are merely close.
+    LInf,
+}
+
+fn converged(f: &DVector<f64>, tol: f64, norm: ConvergenceNorm) -> bool {
+    match norm {
+        ConvergenceNorm::L2 => f.norm() < tol,
+        ConvergenceNorm::LInf => f.iter().fold(0.0_f64, |m, r| m.max(r.abs())) < tol,
+    }
+}
+
+/// Tunable knobs of the LM iteration.
+#[derive(Debug, Clone, Copy)]
+pub struct LmConfig {
+    pub tol: f64,
+    pub max_iters: usize,
+    pub finite_diff_step: f64,
+    pub lambda0: f64,
+    pub lambda_up: f64,
+    pub lambda_down: f64,
+    pub convergence: ConvergenceNorm,
+}
+
+impl Default for LmConfig {
+    fn default() -> Self {
+        Self {
+            tol: 1e-2,
+            max_iters: 30,
+            finite_diff_step: 1e-3,
+            lambda0: 1e-2,
+            lambda_up: 10.0,
+            lambda_down: 0.1,
+            convergence: ConvergenceNorm::L2,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LmReport {
+    pub x: Vec<f64>,
+    pub residual: Vec<f64>,
+    pub residual_norm: f64,
+    pub converged: bool,
+    pub iterations: usize,
+}
+
+/// Finite-difference Jacobian of `residual_fn` at `x`, with `step_fn(x, j)`
+/// giving the perturbation for column `j` (fixed per-variable, or a function
+/// of the current `x` for relative stepping). `pub(crate)` so
+/// [`super::target::solve_to_target`]'s SVD corrector reuses it too.
+pub(crate) fn finite_diff_jacobian(
+    x: &DVector<f64>,
+    f: &DVector<f64>,
+    step_fn: &dyn Fn(&DVector<f64>, usize) -> f64,
+    residual_fn: &dyn Fn(&DVector<f64>) -> DVector<f64>,
+) -> DMatrix<f64> {
+    let n_ctrl = x.nrows();
+    let n_res = f.nrows();
+    let mut jac = DMatrix::zeros(n_res, n_ctrl);
+    for j in 0..n_ctrl {
+        let step = step_fn(x, j);
+        let mut x_pert = x.clone();
+        x_pert[j] += step;
+        let f_pert = residual_fn(&x_pert);
+        for i in 0..n_res {
+            jac[(i, j)] = (f_pert[i] - f[i]) / step;
+        }
+    }
+    jac
+}
+
+/// Core damped least-squares loop, generic over whatever `residual_fn`
+/// computes (a launch sim, an orbit propagation, anything deterministic).
+/// `bounds[j] = (min, max)` clamps variable `j` after every step if given.
+/// `config.convergence` picks the stop-condition norm ([`ConvergenceNorm`]);
+/// the damped-step accept/reject decision is always L2, independent of it.
+/// `pub(crate)` so the other targeting modules in `gnc_mod` share this
+/// instead of each reimplementing it.
+pub(crate) fn levenberg_marquardt(
+    x0: DVector<f64>,
+    bounds: Option<&[(f64, f64)]>,
+    config: LmConfig,
+    step_fn: impl Fn(&DVector<f64>, usize) -> f64,
+    residual_fn: impl Fn(&DVector<f64>) -> DVector<f64>,
+) -> LmReport {
+    let clamp = |v: &mut DVector<f64>| {
+        if let Some(b) = bounds {
+            for i in 0..v.nrows() {
+                v[i] = v[i].clamp(b[i].0, b[i].1);
+            }
+        }
+    };
+
+    let mut x = x0;
+    let mut f = residual_fn(&x);
+    let mut lambda = config.lambda0;
+    let mut iterations = 0;
+
+    for _ in 0..config.max_iters {
+        iterations += 1;
+        let norm = f.norm();
+        if converged(&f, config.tol, config.convergence) {
+            break;
+        }
+
+        let jac = finite_diff_jacobian(&x, &f, &step_fn, &residual_fn);
+        let jt = jac.transpose();
+        let jtj = &jt * &jac;
+        let jtr = &jt * &f;
+
+        // Try the damped step; if it doesn't improve the residual, grow
+        // lambda and retry until it does or lambda runs away.
+        loop {
+            let mut damped = jtj.clone();
+            for i in 0..damped.nrows() {
+                damped[(i, i)] += lambda * jtj[(i, i)].max(1e-12);
+            }
+            let step = match damped.lu().solve(&(-&jtr)) {
+                Some(s) => s,
+                None => {
+                    lambda *= config.lambda_up;
+                    if lambda > 1e12 {
+                        break;
+                    }
+                    continue;
+                }
+            };
+
+            let mut candidate = &x + &step;
+            clamp(&mut candidate);
+            let f_candidate = residual_fn(&candidate);
+            if f_candidate.norm() < norm {
+                x = candidate;
+                f = f_candidate;
+                lambda *= config.lambda_down;
+                break;
+            } else {
+                lambda *= config.lambda_up;
+                if lambda > 1e12 {
+                    // Stuck: accept no further progress this iteration.
+                    break;
+                }
+            }
+        }
+    }
+
+    LmReport {
+        x: x.iter().copied().collect(),
+        residual: f.iter().copied().collect(),
+        residual_norm: f.norm(),
+        converged: converged(&f, config.tol, config.convergence),
+        iterations,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Ascent pitch-program targeting
+// ---------------------------------------------------------------------------
+
+fn pitch_program_to_vec(p: PitchProgram) -> DVector<f64> {
+    DVector::from_vec(vec![p.t_vertical, p.t_pitchover_end, p.target_pitch])
+}
+
+fn vec_to_pitch_program(v: &DVector<f64>) -> PitchProgram {
+    PitchProgram { t_vertical: v[0], t_pitchover_end: v[1], target_pitch: v[2] }
+}
+
+fn ascent_residual(v: &DVector<f64>, mission: &Mission, sim_config: &SimConfig, targets: &Targets) -> DVector<f64> {
+    let program = vec_to_pitch_program(v);
+    let mut tvc = TvcController::with_program(program);
+    let (trajectory, _) = simulate_with(mission, sim_config, &mut tvc);
+
+    let apogee = trajectory.iter().map(|s| s.pos.z).fold(f64::MIN, f64::max);
+
+    let mut achieved = Vec::new();
+    let mut desired = Vec::new();
+    if let Some(target) = targets.apogee_m {
+        achieved.push(apogee);
+        desired.push(target);
+    }
+    if let Some(target) = targets.burnout_fpa_rad {
+        let burnout = trajectory
+            .iter()
+            .max_by(|a, b| a.vel.norm().partial_cmp(&b.vel.norm()).unwrap())
+            .unwrap();
+        let speed = burnout.vel.norm();
+        achieved.push(if speed < 1.0 { 0.0 } else { (burnout.vel.z / speed).asin() });
+        desired.push(target);
+    }
+
+    DVector::from_vec(achieved) - DVector::from_vec(desired)
+}
+
+/// Tune the full [`PitchProgram`] (all three knots) to hit `targets`, via
+/// classic Levenberg-Marquardt rather than [`super::target::solve_to_target`]'s
+/// fixed two-knot SVD corrector.
+pub fn solve_ascent_lm(
+    mission: &Mission,
+    sim_config: &SimConfig,
+    targets: Targets,
+    initial: PitchProgram,
+    config: LmConfig,
+) -> (PitchProgram, LmReport) {
+    let report = levenberg_marquardt(
+        pitch_program_to_vec(initial),
+        None,
+        config,
+        |_, _| config.finite_diff_step,
+        |v| ascent_residual(v, mission, sim_config, &targets),
+    );
+    let program = vec_to_pitch_program(&DVector::from_vec(report.x.clone()));
+    (program, report)
+}
+
+// ---------------------------------------------------------------------------
+// Orbital burn targeting
+// ---------------------------------------------------------------------------
+
+/// Apply an instantaneous delta-v, propagate, and report how close the
+/// resulting orbit's apoapsis radius came to `target_radius_m`.
+fn burn_residual(
+    dv: &DVector<f64>,
+    initial: &OrbitalState,
+    dt: f64,
+    duration: f64,
+    use_j2: bool,
+    target_radius_m: f64,
+) -> DVector<f64> {
+    let burned = OrbitalState {
+        time: initial.time,
+        pos: initial.pos,
+        vel: initial.vel + nalgebra::Vector3::new(dv[0], dv[1], dv[2]),
+    };
+    let trajectory = propagate_orbit(&burned, dt, duration, use_j2, None);
+    let apoapsis = trajectory.iter().map(|s| s.pos.norm()).fold(f64::MIN, f64::max);
+    DVector::from_vec(vec![apoapsis - target_radius_m])
+}
+
+/// Tune a burn delta-v vector (applied at `initial`) so the resulting orbit's
+/// apoapsis radius hits `target_radius_m`, by propagating forward `duration`
+/// seconds and Jacobian-stepping the three delta-v components.
+pub fn solve_orbital_burn_lm(
+    initial: &OrbitalState,
+    dt: f64,
+    duration: f64,
+    use_j2: bool,
+    target_radius_m: f64,
+    initial_dv: nalgebra::Vector3<f64>,
+    config: LmConfig,
+) -> (nalgebra::Vector3<f64>, LmReport) {
+    let x0 = DVector::from_vec(vec![initial_dv.x, initial_dv.y, initial_dv.z]);
+    let report = levenberg_marquardt(
+        x0,
+        None,
+        config,
+        |_, _| config.finite_diff_step,
+        |v| burn_residual(v, initial, dt, duration, use_j2, target_radius_m),
+    );
+    let dv = nalgebra::Vector3::new(report.x[0], report.x[1], report.x[2]);
+    (dv, report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::gravity::{MU_EARTH, R_EARTH_ECI};
+    use crate::vehicle::presets;
+    use nalgebra::Vector3;
+
+    #[test]
+    fn ascent_lm_converges_on_apogee_target() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 120.0 };
+        let initial = PitchProgram::default();
+
+        let baseline = ascent_residual(
+            &pitch_program_to_vec(initial),
+            &mission,
+            &sim_config,
+            &Targets { apogee_m: Some(0.0), ..Default::default() },
+        );
+        let target_apogee = baseline[0] * 0.8;
+
+        let targets = Targets { apogee_m: Some(target_apogee), ..Default::default() };
+        let (_, report) = solve_ascent_lm(
+            &mission,
+            &sim_config,
+            targets,
+            initial,
+            LmConfig { max_iters: 15, tol: 50.0, ..Default::default() },
+        );
+
+        assert!(report.residual_norm < (baseline[0] - target_apogee).abs());
+    }
+
+    #[test]
+    fn orbital_burn_lm_raises_apoapsis() {
+        let r = R_EARTH_ECI + 400_000.0;
+        let v = (MU_EARTH / r).sqrt();
+        let initial = OrbitalState {
+            time: 0.0,
+            pos: Vector3::new(r, 0.0, 0.0),
+            vel: Vector3::new(0.0, v, 0.0),
+        };
+        let period = 2.0 * std::f64::consts::PI * (r.powi(3) / MU_EARTH).sqrt();
+        let target_radius = r + 50_000.0;
+
+        let (dv, report) = solve_orbital_burn_lm(
+            &initial,
+            5.0,
+            period / 2.0,
+            false,
+            target_radius,
+            Vector3::new(0.0, 10.0, 0.0),
+            LmConfig { max_iters: 15, tol: 500.0, ..Default::default() },
+        );
+
+        assert!(report.residual_norm < 50_000.0, "should move toward target apoapsis, residual {}", report.residual_norm);
+        assert!(dv.norm() > 0.0);
+    }
+
+    #[test]
+    fn lm_converges_on_simple_quadratic() {
+        // Residual r(x) = x - 3, trivial sanity check of the core loop.
+        let config = LmConfig { max_iters: 20, tol: 1e-6, ..Default::default() };
+        let report = levenberg_marquardt(
+            DVector::from_vec(vec![0.0]),
+            None,
+            config,
+            |_, _| config.finite_diff_step,
+            |v| DVector::from_vec(vec![v[0] - 3.0]),
+        );
+        assert!(report.converged);
+        assert!((report.x[0] - 3.0).abs() < 1e-4);
+    }
+}
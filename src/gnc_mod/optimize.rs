@@ -0,0 +1,262 @@
+use crate::dynamics::state::SimConfig;
+use crate::rng::Rng;
+use crate::sim::runner::simulate_with;
+use crate::vehicle::Mission;
+use super::ga_core::{ga_search, GaSearchConfig, Genome};
+use super::guidance::PitchProgram;
+use super::tvc::TvcController;
+
+// ---------------------------------------------------------------------------
+// Genetic-algorithm ascent optimizer over the pitch program
+// ---------------------------------------------------------------------------
+//
+// A genome is the three free knots of `PitchProgram`, searched as a flat
+// `[t_vertical, t_pitchover_end, target_pitch]` vector. Fitness comes from
+// flying the genome through `sim::simulate_with` with a `TvcController` built
+// from it and scoring the resulting trajectory against the chosen objective.
+// The generation loop itself is [`super::ga_core::ga_search`]; this module
+// only implements [`Genome`] for the pitch-program vector and decodes the
+// winner back into a [`PitchProgram`].
+
+const GENOME_LEN: usize = 3;
+
+/// Inclusive search bounds for each gene, in the same order as the genome.
+#[derive(Debug, Clone, Copy)]
+pub struct Bounds {
+    pub t_vertical: (f64, f64),
+    pub t_pitchover_end: (f64, f64),
+    pub target_pitch: (f64, f64), // rad
+}
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Self {
+            t_vertical: (0.5, 10.0),
+            t_pitchover_end: (5.0, 60.0),
+            target_pitch: (5.0_f64.to_radians(), 85.0_f64.to_radians()),
+        }
+    }
+}
+
+impl Bounds {
+    fn lo(&self) -> [f64; GENOME_LEN] {
+        [self.t_vertical.0, self.t_pitchover_end.0, self.target_pitch.0]
+    }
+
+    fn hi(&self) -> [f64; GENOME_LEN] {
+        [self.t_vertical.1, self.t_pitchover_end.1, self.target_pitch.1]
+    }
+}
+
+/// What the GA is trying to achieve.
+#[derive(Debug, Clone, Copy)]
+pub enum Objective {
+    /// Fly as high as possible.
+    MaxApogee,
+    /// Hit a specific apogee, penalizing downrange distance at apogee.
+    TargetApogee { altitude_m: f64, downrange_weight: f64 },
+}
+
+/// Tunable knobs of the search itself.
+#[derive(Debug, Clone, Copy)]
+pub struct GaConfig {
+    pub population: usize,
+    pub generations: usize,
+    pub elite_count: usize,
+    pub tournament_size: usize,
+    pub mutation_rate: f64,
+    pub mutation_std_frac: f64, // mutation std as a fraction of each gene's range
+    pub stall_generations: usize, // stop early if best fitness hasn't improved in this many gens
+    pub seed: u64,
+}
+
+impl Default for GaConfig {
+    fn default() -> Self {
+        Self {
+            population: 50,
+            generations: 40,
+            elite_count: 2,
+            tournament_size: 3,
+            mutation_rate: 0.2,
+            mutation_std_frac: 0.1,
+            stall_generations: 10,
+            seed: 0,
+        }
+    }
+}
+
+/// Best genome found, decoded back into a [`PitchProgram`], plus its trajectory.
+#[derive(Debug, Clone)]
+pub struct OptimizeResult {
+    pub program: PitchProgram,
+    pub fitness: f64,
+    pub trajectory: Vec<crate::dynamics::state::State>,
+}
+
+fn genome_to_program(genome: &[f64; GENOME_LEN]) -> PitchProgram {
+    PitchProgram {
+        t_vertical: genome[0],
+        t_pitchover_end: genome[1],
+        target_pitch: genome[2],
+    }
+}
+
+/// Push the genome's ordering constraint (`t_pitchover_end` must exceed
+/// `t_vertical`) back into a valid region rather than letting crossover or
+/// mutation produce a genome whose pitchover ends before it starts.
+fn repair(genome: &mut [f64; GENOME_LEN], bounds: &Bounds) {
+    let lo = bounds.lo();
+    let hi = bounds.hi();
+    for i in 0..GENOME_LEN {
+        genome[i] = genome[i].clamp(lo[i], hi[i]);
+    }
+    if genome[1] <= genome[0] {
+        genome[1] = (genome[0] + 1.0).min(hi[1]);
+    }
+}
+
+fn random_genome(rng: &mut Rng, bounds: &Bounds) -> [f64; GENOME_LEN] {
+    let lo = bounds.lo();
+    let hi = bounds.hi();
+    let mut g = [0.0; GENOME_LEN];
+    for i in 0..GENOME_LEN {
+        g[i] = rng.uniform(lo[i], hi[i]);
+    }
+    repair(&mut g, bounds);
+    g
+}
+
+/// Fly one genome and score it against `objective`. Genomes whose sim ends
+/// immediately (never leaves the pad) are penalized rather than crashing the
+/// search, since a zero-length trajectory carries no apogee information.
+fn fitness_of(genome: &[f64; GENOME_LEN], mission: &Mission, config: &SimConfig, objective: Objective) -> f64 {
+    let program = genome_to_program(genome);
+    let mut controller = TvcController::with_program(program);
+    let (trajectory, _) = simulate_with(mission, config, &mut controller);
+
+    let apogee = trajectory.iter().map(|s| s.pos.z).fold(f64::MIN, f64::max);
+    if !apogee.is_finite() || apogee < 1.0 {
+        return -1e9;
+    }
+
+    match objective {
+        Objective::MaxApogee => apogee,
+        Objective::TargetApogee { altitude_m, downrange_weight } => {
+            let apogee_state = trajectory
+                .iter()
+                .max_by(|a, b| a.pos.z.partial_cmp(&b.pos.z).unwrap())
+                .unwrap();
+            let downrange = (apogee_state.pos.x.powi(2) + apogee_state.pos.y.powi(2)).sqrt();
+            -((apogee - altitude_m).abs()) - downrange_weight * downrange
+        }
+    }
+}
+
+fn crossover(rng: &mut Rng, a: &[f64; GENOME_LEN], b: &[f64; GENOME_LEN]) -> [f64; GENOME_LEN] {
+    let mut child = [0.0; GENOME_LEN];
+    for i in 0..GENOME_LEN {
+        let w = rng.next_f64();
+        child[i] = w * a[i] + (1.0 - w) * b[i];
+    }
+    child
+}
+
+fn mutate(rng: &mut Rng, genome: &mut [f64; GENOME_LEN], bounds: &Bounds, mutation_rate: f64, mutation_std_frac: f64) {
+    let lo = bounds.lo();
+    let hi = bounds.hi();
+    for i in 0..GENOME_LEN {
+        if rng.next_f64() < mutation_rate {
+            let std = (hi[i] - lo[i]) * mutation_std_frac;
+            genome[i] = rng.gauss(genome[i], std);
+        }
+    }
+}
+
+impl Genome for [f64; GENOME_LEN] {
+    type Bounds = Bounds;
+
+    fn random(rng: &mut Rng, bounds: &Bounds) -> Self {
+        random_genome(rng, bounds)
+    }
+
+    fn crossover(rng: &mut Rng, a: &Self, b: &Self) -> Self {
+        crossover(rng, a, b)
+    }
+
+    fn mutate(rng: &mut Rng, genome: &mut Self, bounds: &Bounds, mutation_rate: f64, mutation_std_frac: f64) {
+        mutate(rng, genome, bounds, mutation_rate, mutation_std_frac)
+    }
+
+    fn repair(genome: &mut Self, bounds: &Bounds) {
+        repair(genome, bounds)
+    }
+}
+
+/// Tune [`PitchProgram`] for `mission` against `objective` using a genetic
+/// algorithm, seeded for reproducibility.
+pub fn optimize_ascent(
+    mission: &Mission,
+    sim_config: &SimConfig,
+    objective: Objective,
+    bounds: Bounds,
+    ga_config: GaConfig,
+) -> OptimizeResult {
+    let search_config = GaSearchConfig {
+        population: ga_config.population,
+        generations: ga_config.generations,
+        elite_count: ga_config.elite_count,
+        tournament_size: ga_config.tournament_size,
+        mutation_rate: ga_config.mutation_rate,
+        mutation_std_frac: ga_config.mutation_std_frac,
+        stall_generations: Some(ga_config.stall_generations),
+        seed: ga_config.seed,
+    };
+
+    let (best_genome, best_fitness) =
+        ga_search(&bounds, &search_config, |g| fitness_of(g, mission, sim_config, objective));
+
+    let program = genome_to_program(&best_genome);
+    let mut controller = TvcController::with_program(program);
+    let (trajectory, _) = simulate_with(mission, sim_config, &mut controller);
+
+    OptimizeResult { program, fitness: best_fitness, trajectory }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vehicle::presets;
+
+    #[test]
+    fn repair_fixes_inverted_ordering() {
+        let bounds = Bounds::default();
+        let mut genome = [3.0, 1.0, 0.5];
+        repair(&mut genome, &bounds);
+        assert!(genome[1] > genome[0]);
+    }
+
+    #[test]
+    fn same_seed_reproduces_result() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 60.0 };
+        let ga_config = GaConfig { population: 8, generations: 3, ..GaConfig::default() };
+
+        let a = optimize_ascent(&mission, &sim_config, Objective::MaxApogee, Bounds::default(), ga_config);
+        let b = optimize_ascent(&mission, &sim_config, Objective::MaxApogee, Bounds::default(), ga_config);
+
+        assert!((a.fitness - b.fitness).abs() < 1e-9);
+        assert!((a.program.t_vertical - b.program.t_vertical).abs() < 1e-9);
+    }
+
+    #[test]
+    fn optimized_program_respects_bounds() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 60.0 };
+        let bounds = Bounds::default();
+        let ga_config = GaConfig { population: 8, generations: 3, ..GaConfig::default() };
+
+        let result = optimize_ascent(&mission, &sim_config, Objective::MaxApogee, bounds, ga_config);
+        assert!(result.program.t_vertical >= bounds.t_vertical.0 && result.program.t_vertical <= bounds.t_vertical.1);
+        assert!(result.program.t_pitchover_end > result.program.t_vertical);
+    }
+}
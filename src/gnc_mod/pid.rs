@@ -7,27 +7,95 @@ pub struct Pid {
     pub kp: f64,
     pub ki: f64,
     pub kd: f64,
+    pub output_limit: Option<f64>, // total output clamp, ±limit; None = unclamped
+    pub integral_limit: f64,       // raw integral accumulator clamp, ±limit
+    pub derivative_tau: f64,       // low-pass filter time constant, s; 0 = unfiltered
     integral: f64,
-    prev_error: f64,
+    prev_measurement: f64,
+    d_filt: f64,
+    has_prev: bool,
 }
 
 impl Pid {
     pub fn new(kp: f64, ki: f64, kd: f64) -> Self {
-        Self { kp, ki, kd, integral: 0.0, prev_error: 0.0 }
+        Self {
+            kp,
+            ki,
+            kd,
+            output_limit: None,
+            integral_limit: 1.0,
+            derivative_tau: 0.0,
+            integral: 0.0,
+            prev_measurement: 0.0,
+            d_filt: 0.0,
+            has_prev: false,
+        }
     }
 
+    /// Same as [`Pid::new`] but with output saturation, an explicit integral
+    /// clamp, and a derivative low-pass time constant — useful near physical
+    /// limits like gimbal stops where windup and derivative kick matter.
+    pub fn with_limits(kp: f64, ki: f64, kd: f64, output_limit: f64, integral_limit: f64, derivative_tau: f64) -> Self {
+        Self {
+            output_limit: Some(output_limit),
+            integral_limit,
+            derivative_tau,
+            ..Self::new(kp, ki, kd)
+        }
+    }
+
+    /// Update from a bare error term. Equivalent to derivative-on-measurement
+    /// with a measurement of `-error`, which reduces to the classic
+    /// derivative-on-error term — kept for callers that don't track a
+    /// separate process measurement.
     pub fn update(&mut self, error: f64, dt: f64) -> f64 {
-        self.integral += error * dt;
-        // Anti-windup: clamp integral to prevent saturation
-        self.integral = self.integral.clamp(-1.0, 1.0);
-        let derivative = if dt > 0.0 { (error - self.prev_error) / dt } else { 0.0 };
-        self.prev_error = error;
-        self.kp * error + self.ki * self.integral + self.kd * derivative
+        self.update_measurement(error, -error, dt)
+    }
+
+    /// Update using the raw process measurement for the derivative term
+    /// (derivative-on-measurement) so a jump in setpoint doesn't produce a
+    /// derivative-kick spike, then apply conditional-integration anti-windup
+    /// and output saturation.
+    pub fn update_measurement(&mut self, error: f64, measurement: f64, dt: f64) -> f64 {
+        let raw_deriv = if self.has_prev && dt > 0.0 {
+            -(measurement - self.prev_measurement) / dt
+        } else {
+            0.0
+        };
+        self.prev_measurement = measurement;
+        self.has_prev = true;
+
+        if self.derivative_tau > 0.0 && dt > 0.0 {
+            let alpha = dt / (self.derivative_tau + dt);
+            self.d_filt += alpha * (raw_deriv - self.d_filt);
+        } else {
+            self.d_filt = raw_deriv;
+        }
+
+        let candidate_integral = (self.integral + error * dt).clamp(-self.integral_limit, self.integral_limit);
+        let p = self.kp * error;
+        let d = self.kd * self.d_filt;
+        let unsaturated_output = p + self.ki * candidate_integral + d;
+
+        let output = match self.output_limit {
+            Some(limit) => unsaturated_output.clamp(-limit, limit),
+            None => unsaturated_output,
+        };
+
+        // Conditional-integration anti-windup: only keep the new integral
+        // term if it didn't push the output past saturation.
+        if self.output_limit.map_or(true, |limit| unsaturated_output.abs() <= limit) {
+            self.integral = candidate_integral;
+        }
+
+        output
     }
 
     pub fn reset(&mut self) {
         self.integral = 0.0;
-        self.prev_error = 0.0;
+        self.prev_measurement = 0.0;
+        self.d_filt = 0.0;
+        self.has_prev = false;
     }
 }
 
@@ -49,4 +117,49 @@ mod tests {
         let out = pid.update(1.0, 0.1);
         assert!((out - 0.2).abs() < 1e-10, "Integral should accumulate");
     }
+
+    #[test]
+    fn output_limit_clamps_total() {
+        let mut pid = Pid::with_limits(10.0, 0.0, 0.0, 1.0, 1.0, 0.0);
+        let out = pid.update(5.0, 0.01);
+        assert!((out - 1.0).abs() < 1e-10, "Output should saturate at the limit");
+    }
+
+    #[test]
+    fn anti_windup_stops_integrating_past_saturation() {
+        let mut pid = Pid::with_limits(0.0, 1.0, 0.0, 0.5, 10.0, 0.0);
+        for _ in 0..20 {
+            pid.update(1.0, 0.1);
+        }
+        // Without anti-windup the integral would keep growing past the point
+        // needed to saturate; with it, the output stays pinned at the limit
+        // and the integral stops accumulating once saturated.
+        let out = pid.update(1.0, 0.1);
+        assert!((out - 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn derivative_filter_smooths_noisy_measurement() {
+        let mut filtered = Pid::with_limits(0.0, 0.0, 1.0, 100.0, 1.0, 0.5);
+        let mut unfiltered = Pid::new(0.0, 0.0, 1.0);
+
+        filtered.update_measurement(0.0, 0.0, 0.01);
+        unfiltered.update_measurement(0.0, 0.0, 0.01);
+
+        // A sudden measurement jump should produce a smaller immediate
+        // derivative kick through the filtered PID than the unfiltered one.
+        let out_filtered = filtered.update_measurement(0.0, 1.0, 0.01).abs();
+        let out_unfiltered = unfiltered.update_measurement(0.0, 1.0, 0.01).abs();
+        assert!(out_filtered < out_unfiltered);
+    }
+
+    #[test]
+    fn setpoint_jump_causes_no_derivative_kick_on_measurement() {
+        let mut pid = Pid::new(0.0, 0.0, 1.0);
+        pid.update_measurement(0.0, 5.0, 0.1);
+        // Setpoint jumps (error changes) but measurement stays put: the
+        // derivative-on-measurement term should stay zero.
+        let out = pid.update_measurement(10.0, 5.0, 0.1);
+        assert!(out.abs() < 1e-10, "no measurement change means no derivative term");
+    }
 }
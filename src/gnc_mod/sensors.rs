@@ -0,0 +1,182 @@
+use nalgebra::{UnitQuaternion, Vector3};
+
+use crate::dynamics::state::{GncCommand, State};
+use crate::rng::Rng;
+use crate::vehicle::Mission;
+use super::Controller;
+
+// ---------------------------------------------------------------------------
+// IMU sensor-noise layer
+// ---------------------------------------------------------------------------
+//
+// `TvcController::update` (and every other [`Controller`]) normally reads the
+// exact truth `State`, which is unrealistically perfect for GNC testing.
+// `ImuModel` sits between truth and a controller, returning a measured state
+// with gyro noise/bias on `omega`, small-angle attitude noise/bias standing
+// in for accelerometer error, and an update-rate limit so the controller
+// sees a held, stale sample between sensor ticks.
+
+/// Noise/bias parameters for the simulated IMU. All fields default to zero
+/// noise and an always-fresh update rate, so wrapping a controller with the
+/// default config is a no-op.
+#[derive(Debug, Clone, Copy)]
+pub struct NoiseConfig {
+    pub gyro_sigma: f64,         // rad/s, 1-sigma gyro noise
+    pub gyro_bias: Vector3<f64>, // rad/s, constant per-axis gyro bias
+    pub accel_sigma: f64,        // rad, 1-sigma attitude-sensing noise
+    pub accel_bias: Vector3<f64>, // rad, constant per-axis attitude bias
+    pub rate_hz: f64,            // sensor update rate; <= 0 means every sim step
+}
+
+impl Default for NoiseConfig {
+    fn default() -> Self {
+        Self {
+            gyro_sigma: 0.0,
+            gyro_bias: Vector3::zeros(),
+            accel_sigma: 0.0,
+            accel_bias: Vector3::zeros(),
+            rate_hz: 0.0,
+        }
+    }
+}
+
+/// Seedable IMU model: turns truth state into a measured state, honoring
+/// `NoiseConfig`'s update-rate limit by holding the last sample between ticks.
+#[derive(Debug, Clone)]
+pub struct ImuModel {
+    config: NoiseConfig,
+    rng: Rng,
+    next_update: f64,
+    held: Option<State>,
+}
+
+impl ImuModel {
+    pub fn new(config: NoiseConfig, seed: u64) -> Self {
+        Self { config, rng: Rng::seeded(seed), next_update: 0.0, held: None }
+    }
+
+    /// Measured state at `truth.time`: resampled with noise when a sensor
+    /// tick is due, otherwise the last held sample.
+    pub fn measure(&mut self, truth: &State) -> State {
+        let due = self.held.is_none() || self.config.rate_hz <= 0.0 || truth.time >= self.next_update;
+        if due {
+            let interval = if self.config.rate_hz > 0.0 { 1.0 / self.config.rate_hz } else { 0.0 };
+            self.next_update = truth.time + interval;
+            self.held = Some(self.noisy_sample(truth));
+        }
+        self.held.clone().unwrap()
+    }
+
+    fn noisy_sample(&mut self, truth: &State) -> State {
+        let mut measured = truth.clone();
+
+        measured.omega = Vector3::new(
+            self.rng.gauss(truth.omega.x + self.config.gyro_bias.x, self.config.gyro_sigma),
+            self.rng.gauss(truth.omega.y + self.config.gyro_bias.y, self.config.gyro_sigma),
+            self.rng.gauss(truth.omega.z + self.config.gyro_bias.z, self.config.gyro_sigma),
+        );
+
+        // Attitude-sensing error, modeled as a small-angle rotation applied
+        // on top of the true attitude.
+        let dtheta = Vector3::new(
+            self.rng.gauss(self.config.accel_bias.x, self.config.accel_sigma),
+            self.rng.gauss(self.config.accel_bias.y, self.config.accel_sigma),
+            self.rng.gauss(self.config.accel_bias.z, self.config.accel_sigma),
+        );
+        measured.quat = truth.quat * UnitQuaternion::from_scaled_axis(dtheta);
+
+        measured
+    }
+
+    pub fn reset(&mut self) {
+        self.next_update = 0.0;
+        self.held = None;
+    }
+}
+
+/// Wraps any [`Controller`] so it only ever sees sensor-noised state,
+/// letting users study closed-loop robustness of PID gains to realistic
+/// sensor error without modifying the controller itself.
+pub struct NoisyController<C: Controller> {
+    inner: C,
+    imu: ImuModel,
+}
+
+impl<C: Controller> NoisyController<C> {
+    pub fn new(inner: C, config: NoiseConfig, seed: u64) -> Self {
+        Self { inner, imu: ImuModel::new(config, seed) }
+    }
+}
+
+impl<C: Controller> Controller for NoisyController<C> {
+    fn control(&mut self, state: &State, mission: &Mission, dt: f64) -> GncCommand {
+        let measured = self.imu.measure(state);
+        self.inner.control(&measured, mission, dt)
+    }
+
+    fn reset(&mut self) {
+        self.imu.reset();
+        self.inner.reset();
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn truth_state(time: f64) -> State {
+        State {
+            time,
+            pos: Vector3::zeros(),
+            vel: Vector3::zeros(),
+            quat: UnitQuaternion::identity(),
+            omega: Vector3::new(0.1, 0.2, 0.3),
+            mass: 20.0,
+            stage_idx: 0,
+            stage_ignition_time: 0.0,
+        }
+    }
+
+    #[test]
+    fn zero_noise_config_is_transparent() {
+        let mut imu = ImuModel::new(NoiseConfig::default(), 0);
+        let truth = truth_state(1.0);
+        let measured = imu.measure(&truth);
+        assert!((measured.omega - truth.omega).norm() < 1e-12);
+        assert!((measured.quat.angle_to(&truth.quat)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gyro_noise_perturbs_omega() {
+        let config = NoiseConfig { gyro_sigma: 0.05, ..NoiseConfig::default() };
+        let mut imu = ImuModel::new(config, 1);
+        let truth = truth_state(1.0);
+        let measured = imu.measure(&truth);
+        assert!((measured.omega - truth.omega).norm() > 1e-9);
+    }
+
+    #[test]
+    fn rate_limit_holds_stale_sample_between_ticks() {
+        let config = NoiseConfig { gyro_sigma: 0.05, rate_hz: 1.0, ..NoiseConfig::default() };
+        let mut imu = ImuModel::new(config, 2);
+        let first = imu.measure(&truth_state(0.0));
+        let held = imu.measure(&truth_state(0.3)); // well within the 1s tick
+        assert_eq!(first.omega, held.omega);
+
+        let refreshed = imu.measure(&truth_state(1.5)); // past the next tick
+        assert_ne!(refreshed.time, held.time);
+    }
+
+    #[test]
+    fn gyro_bias_shifts_measured_rate() {
+        let config = NoiseConfig { gyro_bias: Vector3::new(0.5, 0.0, 0.0), ..NoiseConfig::default() };
+        let mut imu = ImuModel::new(config, 3);
+        let truth = truth_state(0.0);
+        let measured = imu.measure(&truth);
+        assert!((measured.omega.x - (truth.omega.x + 0.5)).abs() < 1e-9);
+    }
+}
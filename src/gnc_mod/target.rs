@@ -0,0 +1,208 @@
+use nalgebra::DVector;
+
+use crate::dynamics::state::{SimConfig, State};
+use crate::sim::runner::simulate_with;
+use crate::vehicle::Mission;
+use super::guidance::PitchProgram;
+use super::opti::finite_diff_jacobian;
+use super::tvc::TvcController;
+
+// ---------------------------------------------------------------------------
+// Differential-corrector targeting
+// ---------------------------------------------------------------------------
+//
+// A deterministic complement to the GA optimizer in `optimize`: given a
+// handful of terminal conditions to hit exactly (apogee altitude, flight-path
+// angle at burnout), drive the two pitch-program controls with a damped
+// Gauss-Newton corrector `x ← x − α·J⁺F`, where `J⁺` is the Moore-Penrose
+// pseudo-inverse of the finite-difference Jacobian (via SVD) and `α` is
+// backtracked until the step actually reduces the residual. Unlike
+// [`super::opti::levenberg_marquardt`]'s normal-equations core, this fixed
+// two-knot problem is small and well-conditioned enough that the
+// pseudo-inverse step needs no Tikhonov damping of its own, just the
+// backtracking line search; only the Jacobian plumbing
+// ([`finite_diff_jacobian`]) is shared with the LM core.
+
+/// Controls this targeter is allowed to tune: the pitchover end time and the
+/// target pitch angle, with `t_vertical` held at its default.
+#[derive(Debug, Clone, Copy)]
+pub struct Controls {
+    pub t_pitchover_end: f64,
+    pub target_pitch: f64,
+}
+
+impl Controls {
+    fn to_vec(self) -> DVector<f64> {
+        DVector::from_vec(vec![self.t_pitchover_end, self.target_pitch])
+    }
+
+    fn from_vec(v: &DVector<f64>) -> Self {
+        Self { t_pitchover_end: v[0], target_pitch: v[1] }
+    }
+
+    fn to_program(self) -> PitchProgram {
+        PitchProgram {
+            t_pitchover_end: self.t_pitchover_end,
+            target_pitch: self.target_pitch,
+            ..PitchProgram::default()
+        }
+    }
+}
+
+/// Terminal conditions to drive to; unset fields are excluded from the
+/// residual vector (and so don't constrain the solve).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Targets {
+    pub apogee_m: Option<f64>,
+    pub burnout_fpa_rad: Option<f64>,
+}
+
+/// Tunable knobs of the corrector iteration: `initial_damping` is the
+/// starting backtracking step `α` (halved on each rejected step).
+#[derive(Debug, Clone, Copy)]
+pub struct TargetConfig {
+    pub tol: f64,
+    pub max_iters: usize,
+    pub finite_diff_step: f64,
+    pub initial_damping: f64,
+}
+
+impl Default for TargetConfig {
+    fn default() -> Self {
+        Self {
+            tol: 1e-2,
+            max_iters: 25,
+            finite_diff_step: 1e-3,
+            initial_damping: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TargetResult {
+    pub controls: Controls,
+    pub residual: Vec<f64>,
+    pub residual_norm: f64,
+    pub converged: bool,
+}
+
+/// Flight-path angle (rad above local horizontal) at the state of maximum
+/// speed, used as a proxy for "at burnout" since that's where powered flight
+/// hands off to a ballistic arc.
+fn burnout_fpa(trajectory: &[State]) -> f64 {
+    let burnout = trajectory
+        .iter()
+        .max_by(|a, b| a.vel.norm().partial_cmp(&b.vel.norm()).unwrap())
+        .unwrap();
+    let speed = burnout.vel.norm();
+    if speed < 1.0 {
+        0.0
+    } else {
+        (burnout.vel.z / speed).asin()
+    }
+}
+
+fn achieved(controls: Controls, mission: &Mission, sim_config: &SimConfig, targets: &Targets) -> DVector<f64> {
+    let mut tvc = TvcController::with_program(controls.to_program());
+    let (trajectory, _) = simulate_with(mission, sim_config, &mut tvc);
+
+    let apogee = trajectory.iter().map(|s| s.pos.z).fold(f64::MIN, f64::max);
+
+    let mut out = Vec::new();
+    if targets.apogee_m.is_some() {
+        out.push(apogee);
+    }
+    if targets.burnout_fpa_rad.is_some() {
+        out.push(burnout_fpa(&trajectory));
+    }
+    DVector::from_vec(out)
+}
+
+fn desired_vec(targets: &Targets) -> DVector<f64> {
+    let mut out = Vec::new();
+    if let Some(v) = targets.apogee_m {
+        out.push(v);
+    }
+    if let Some(v) = targets.burnout_fpa_rad {
+        out.push(v);
+    }
+    DVector::from_vec(out)
+}
+
+fn residual(controls: Controls, mission: &Mission, sim_config: &SimConfig, targets: &Targets) -> DVector<f64> {
+    achieved(controls, mission, sim_config, targets) - desired_vec(targets)
+}
+
+/// Drive `controls` to the given `targets` with `x ← x − α·J⁺F`: `J` is the
+/// finite-difference Jacobian of [`residual`], `J⁺` its SVD pseudo-inverse,
+/// and `α` backtracks (halving from `config.initial_damping`) until the step
+/// actually reduces `‖F‖`; once `α` drops below `1e-6` that step is taken
+/// even if it does not.
+pub fn solve_to_target(
+    mission: &Mission,
+    sim_config: &SimConfig,
+    targets: Targets,
+    initial: Controls,
+    config: TargetConfig,
+) -> TargetResult {
+    let residual_fn = |x: &DVector<f64>| residual(Controls::from_vec(x), mission, sim_config, &targets);
+
+    let mut x = initial.to_vec();
+    let mut f = residual_fn(&x);
+
+    for _ in 0..config.max_iters {
+        let norm = f.norm();
+        if norm < config.tol {
+            break;
+        }
+
+        let jac = finite_diff_jacobian(&x, &f, &|_, _| config.finite_diff_step, &residual_fn);
+        let pinv = match jac.svd(true, true).pseudo_inverse(1e-12) {
+            Ok(p) => p,
+            Err(_) => break,
+        };
+        let step = &pinv * &f;
+
+        let mut alpha = config.initial_damping;
+        loop {
+            let candidate = &x - alpha * &step;
+            let f_candidate = residual_fn(&candidate);
+            if f_candidate.norm() < norm || alpha < 1e-6 {
+                x = candidate;
+                f = f_candidate;
+                break;
+            }
+            alpha *= 0.5;
+        }
+    }
+
+    TargetResult {
+        controls: Controls::from_vec(&x),
+        residual: f.iter().copied().collect(),
+        residual_norm: f.norm(),
+        converged: f.norm() < config.tol,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vehicle::presets;
+
+    #[test]
+    fn converges_on_apogee_target() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 120.0 };
+        let initial = Controls { t_pitchover_end: 15.0, target_pitch: 45.0_f64.to_radians() };
+
+        // Run once to find an achievable apogee near the default program, then
+        // target something a bit different to confirm the solver moves toward it.
+        let baseline = achieved(initial, &mission, &sim_config, &Targets { apogee_m: Some(0.0), ..Default::default() });
+        let target_apogee = baseline[0] * 0.8;
+
+        let targets = Targets { apogee_m: Some(target_apogee), ..Default::default() };
+        let result = solve_to_target(&mission, &sim_config, targets, initial, TargetConfig { max_iters: 15, tol: 50.0, ..Default::default() });
+
+        assert!(result.residual_norm < (baseline[0] - target_apogee).abs());
+    }
+}
@@ -0,0 +1,255 @@
+use nalgebra::DVector;
+
+use crate::dynamics::state::SimConfig;
+use crate::vehicle::Mission;
+use super::guidance::PitchProgram;
+use super::opti::{levenberg_marquardt, ConvergenceNorm, LmConfig};
+use super::pid::Pid;
+use super::tvc::TvcController;
+use crate::sim::runner::simulate_with;
+
+// ---------------------------------------------------------------------------
+// Generic design-variable targeter
+// ---------------------------------------------------------------------------
+//
+// Unlike `opti`'s fixed pitch-program/orbital-burn signatures, here the
+// caller names an arbitrary subset of ascent design variables (pitch-program
+// knots *and* the final-stage TVC gains) and an arbitrary subset of terminal
+// objectives, each with its own finite-difference perturbation, bounds, and
+// tolerance. The LM iteration itself is [`super::opti::levenberg_marquardt`];
+// this module only builds the residual function and per-objective tolerance
+// scaling on top of it.
+
+/// A design variable this targeter is allowed to tune.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VariableKind {
+    PitchoverEndTime,
+    TargetPitch,
+    PitchKp,
+    PitchKd,
+    YawKp,
+    YawKd,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Variable {
+    pub which: VariableKind,
+    pub initial: f64,
+    pub min: f64,
+    pub max: f64,
+    pub perturbation: f64,
+}
+
+/// A terminal trajectory quantity this targeter can drive to a value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ObjectiveParam {
+    ApogeeAltitude,
+    BurnoutFlightPathAngle,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TargetObjective {
+    pub parameter: ObjectiveParam,
+    pub desired: f64,
+    pub tolerance: f64,
+}
+
+/// Tunable knobs of the LM iteration itself (step damping, not the per-variable perturbations).
+#[derive(Debug, Clone, Copy)]
+pub struct TargeterConfig {
+    pub max_iters: usize,
+    pub lambda0: f64,
+    pub lambda_up: f64,
+    pub lambda_down: f64,
+}
+
+impl Default for TargeterConfig {
+    fn default() -> Self {
+        Self {
+            max_iters: 30,
+            lambda0: 1e-2,
+            lambda_up: 10.0,
+            lambda_down: 0.1,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TargeterResult {
+    pub values: Vec<f64>,
+    pub residual: Vec<f64>,
+    pub residual_norm: f64,
+    pub converged: bool,
+    pub iterations: usize,
+}
+
+/// Baseline ascent setup (default pitch program, default TVC gains) that a
+/// sparse set of [`Variable`]s overrides before each sim run.
+fn build_controller(variables: &[Variable], x: &DVector<f64>) -> TvcController {
+    let mut program = PitchProgram::default();
+    let (mut pitch_kp, mut pitch_kd) = (2.0, 0.5);
+    let (mut yaw_kp, mut yaw_kd) = (2.0, 0.5);
+
+    for (i, v) in variables.iter().enumerate() {
+        let val = x[i].clamp(v.min, v.max);
+        match v.which {
+            VariableKind::PitchoverEndTime => program.t_pitchover_end = val,
+            VariableKind::TargetPitch => program.target_pitch = val,
+            VariableKind::PitchKp => pitch_kp = val,
+            VariableKind::PitchKd => pitch_kd = val,
+            VariableKind::YawKp => yaw_kp = val,
+            VariableKind::YawKd => yaw_kd = val,
+        }
+    }
+
+    TvcController {
+        pitch_pid: Pid::new(pitch_kp, 0.1, pitch_kd),
+        yaw_pid: Pid::new(yaw_kp, 0.1, yaw_kd),
+        law: Box::new(program),
+        accel_limit_g: None,
+    }
+}
+
+fn evaluate(
+    variables: &[Variable],
+    objectives: &[TargetObjective],
+    mission: &Mission,
+    sim_config: &SimConfig,
+    x: &DVector<f64>,
+) -> DVector<f64> {
+    let mut controller = build_controller(variables, x);
+    let (trajectory, _) = simulate_with(mission, sim_config, &mut controller);
+
+    let apogee = || trajectory.iter().map(|s| s.pos.z).fold(f64::MIN, f64::max);
+    let burnout_fpa = || {
+        let burnout = trajectory
+            .iter()
+            .max_by(|a, b| a.vel.norm().partial_cmp(&b.vel.norm()).unwrap())
+            .unwrap();
+        let speed = burnout.vel.norm();
+        if speed < 1.0 { 0.0 } else { (burnout.vel.z / speed).asin() }
+    };
+
+    let residuals: Vec<f64> = objectives
+        .iter()
+        .map(|obj| {
+            let achieved = match obj.parameter {
+                ObjectiveParam::ApogeeAltitude => apogee(),
+                ObjectiveParam::BurnoutFlightPathAngle => burnout_fpa(),
+            };
+            achieved - obj.desired
+        })
+        .collect();
+    DVector::from_vec(residuals)
+}
+
+/// Drive `variables` to satisfy `objectives` via the shared
+/// [`levenberg_marquardt`] core: each residual is pre-scaled by its
+/// objective's own tolerance so a single dimensionless `tol = 1.0` stop
+/// condition covers however many (and whatever unit of) objectives are
+/// named, each variable is perturbed by its own `perturbation`, and bounds
+/// come from each variable's `min`/`max`.
+pub fn solve_targets(
+    mission: &Mission,
+    sim_config: &SimConfig,
+    variables: &[Variable],
+    objectives: &[TargetObjective],
+    config: TargeterConfig,
+) -> TargeterResult {
+    let x0 = DVector::from_vec(variables.iter().map(|v| v.initial).collect());
+    let bounds: Vec<(f64, f64)> = variables.iter().map(|v| (v.min, v.max)).collect();
+
+    let lm_config = LmConfig {
+        tol: 1.0,
+        max_iters: config.max_iters,
+        finite_diff_step: 0.0,
+        lambda0: config.lambda0,
+        lambda_up: config.lambda_up,
+        lambda_down: config.lambda_down,
+        convergence: ConvergenceNorm::LInf,
+    };
+
+    let scaled_residual = |x: &DVector<f64>| {
+        let raw = evaluate(variables, objectives, mission, sim_config, x);
+        DVector::from_iterator(raw.nrows(), raw.iter().zip(objectives).map(|(r, obj)| r / obj.tolerance))
+    };
+
+    let report = levenberg_marquardt(
+        x0,
+        Some(&bounds),
+        lm_config,
+        |_, j| variables[j].perturbation,
+        scaled_residual,
+    );
+
+    let final_x = DVector::from_vec(report.x.clone());
+    let residual = evaluate(variables, objectives, mission, sim_config, &final_x);
+
+    TargeterResult {
+        values: report.x,
+        residual: residual.iter().copied().collect(),
+        residual_norm: residual.norm(),
+        converged: report.converged,
+        iterations: report.iterations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vehicle::presets;
+
+    #[test]
+    fn tunes_pitchover_to_hit_apogee() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 120.0 };
+
+        let variables = vec![Variable {
+            which: VariableKind::PitchoverEndTime,
+            initial: 15.0,
+            min: 1.0,
+            max: 60.0,
+            perturbation: 0.5,
+        }];
+
+        let baseline = evaluate(
+            &variables,
+            &[TargetObjective { parameter: ObjectiveParam::ApogeeAltitude, desired: 0.0, tolerance: 1.0 }],
+            &mission,
+            &sim_config,
+            &DVector::from_vec(vec![15.0]),
+        )[0];
+        let target_apogee = baseline * 0.8;
+
+        let objectives = vec![TargetObjective {
+            parameter: ObjectiveParam::ApogeeAltitude,
+            desired: target_apogee,
+            tolerance: 50.0,
+        }];
+
+        let result = solve_targets(&mission, &sim_config, &variables, &objectives, TargeterConfig { max_iters: 15, ..Default::default() });
+        assert!(result.residual_norm < baseline.abs() * 0.2);
+    }
+
+    #[test]
+    fn variables_stay_within_bounds() {
+        let mission = presets::pathfinder();
+        let sim_config = SimConfig { dt: 0.02, max_time: 60.0 };
+
+        let variables = vec![Variable {
+            which: VariableKind::TargetPitch,
+            initial: 45.0_f64.to_radians(),
+            min: 0.0,
+            max: 30.0_f64.to_radians(),
+            perturbation: 1e-3,
+        }];
+        let objectives = vec![TargetObjective {
+            parameter: ObjectiveParam::ApogeeAltitude,
+            desired: 1e9, // unreachable, forces the solver to push to a bound
+            tolerance: 1.0,
+        }];
+
+        let result = solve_targets(&mission, &sim_config, &variables, &objectives, TargeterConfig { max_iters: 10, ..Default::default() });
+        assert!(result.values[0] >= 0.0 && result.values[0] <= 30.0_f64.to_radians() + 1e-9);
+    }
+}
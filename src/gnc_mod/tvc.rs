@@ -1,16 +1,33 @@
-use crate::dynamics::state::{GncCommand, State};
+use crate::dynamics::state::{GncCommand, State, G0};
 use crate::vehicle::Mission;
-use super::guidance::guidance_pitch;
+use super::guidance::PitchProgram;
+use super::guidance_law::GuidanceLaw;
 use super::pid::Pid;
 
 // ---------------------------------------------------------------------------
 // TVC Controller: guidance + PID control combined
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone)]
 pub struct TvcController {
     pub pitch_pid: Pid,
     pub yaw_pid: Pid,
+    pub law: Box<dyn GuidanceLaw>,
+    /// Closed-loop axial acceleration cap, in g. When set, `update` throttles
+    /// down below whatever `law` requested so `thrust_at(t) * throttle / mass`
+    /// never exceeds `accel_limit_g * G0` — a continuous function of state
+    /// rather than a bang-bang cutoff, same spirit as `GuidanceLaw`'s own
+    /// throttle laws.
+    pub accel_limit_g: Option<f64>,
+}
+
+impl std::fmt::Debug for TvcController {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TvcController")
+            .field("pitch_pid", &self.pitch_pid)
+            .field("yaw_pid", &self.yaw_pid)
+            .field("law", &"<dyn GuidanceLaw>")
+            .finish()
+    }
 }
 
 impl TvcController {
@@ -19,28 +36,76 @@ impl TvcController {
             // Tuned for typical sounding rocket (Ixx~5, nozzle_offset~1m)
             pitch_pid: Pid::new(2.0, 0.1, 0.5),
             yaw_pid: Pid::new(2.0, 0.1, 0.5),
+            law: Box::new(PitchProgram::default()),
+            accel_limit_g: None,
         }
     }
 
+    /// Same as [`TvcController::new`] but capping commanded axial
+    /// acceleration at `max_g` by throttling down.
+    pub fn with_accel_limit(mut self, max_g: f64) -> Self {
+        self.accel_limit_g = Some(max_g);
+        self
+    }
+
+    /// Same as [`TvcController::new`] but flying a custom pitch program
+    /// (used by [`crate::gnc::optimize`] to evaluate candidate genomes).
+    pub fn with_program(program: PitchProgram) -> Self {
+        Self::with_law(Box::new(program))
+    }
+
+    /// Same as [`TvcController::new`] but flying an arbitrary [`GuidanceLaw`].
+    pub fn with_law(law: Box<dyn GuidanceLaw>) -> Self {
+        Self { law, ..Self::new() }
+    }
+
     /// Compute GNC command from current state and mission.
     pub fn update(&mut self, state: &State, mission: &Mission, dt: f64) -> GncCommand {
-        let desired_pitch = guidance_pitch(state, mission);
+        let desired_dir = self.law.direction(state);
+        let desired_pitch = desired_dir.z.atan2(
+            (desired_dir.x.powi(2) + desired_dir.y.powi(2)).sqrt(),
+        );
         let current_pitch = state.pitch();
         let pitch_error = desired_pitch - current_pitch;
 
-        // Yaw: keep zero (no lateral steering for now)
+        let desired_lateral = desired_dir.x.atan2(
+            (desired_dir.y.powi(2) + desired_dir.z.powi(2)).sqrt(),
+        );
         let body_z_inertial = state.body_z();
-        let yaw_error = -body_z_inertial.x.atan2(
+        let current_lateral = body_z_inertial.x.atan2(
             (body_z_inertial.y.powi(2) + body_z_inertial.z.powi(2)).sqrt(),
         );
+        let yaw_error = desired_lateral - current_lateral;
+
+        // Derivative-on-measurement: avoids a derivative-kick spike whenever
+        // the guidance law's desired direction jumps between steps.
+        let gy = self.pitch_pid.update_measurement(pitch_error, current_pitch, dt);
+        let gz = self.yaw_pid.update_measurement(yaw_error, current_lateral, dt);
 
-        let gy = self.pitch_pid.update(pitch_error, dt);
-        let gz = self.yaw_pid.update(yaw_error, dt);
+        let mut throttle = self.law.throttle(state).clamp(0.0, 1.0);
+        if let Some(max_g) = self.accel_limit_g {
+            throttle = throttle.min(self.accel_limited_throttle(state, mission, max_g));
+        }
 
         GncCommand {
             gimbal_y: gy,
             gimbal_z: gz,
+            throttle,
+        }
+    }
+
+    /// Max throttle that keeps `thrust_at(t) * throttle / mass` at or below
+    /// `max_g * G0`, or `1.0` if the stage isn't burning (nothing to limit).
+    fn accel_limited_throttle(&self, state: &State, mission: &Mission, max_g: f64) -> f64 {
+        let stage = match mission.active_stage(state.stage_idx) {
+            Some(s) => s,
+            None => return 1.0,
+        };
+        let full_thrust = stage.thrust_at(state.stage_elapsed());
+        if full_thrust <= 0.0 || state.mass <= 0.0 {
+            return 1.0;
         }
+        (max_g * G0 * state.mass / full_thrust).clamp(0.0, 1.0)
     }
 
     pub fn reset(&mut self) {
@@ -71,3 +136,77 @@ impl super::Controller for TvcController {
 
 /// Backward-compatible type alias.
 pub type GncSystem = TvcController;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::guidance_law::ConstantDirection;
+    use nalgebra::{UnitQuaternion, Vector3};
+
+    fn pad_state() -> State {
+        State {
+            time: 0.0,
+            pos: Vector3::zeros(),
+            vel: Vector3::zeros(),
+            quat: UnitQuaternion::identity(),
+            omega: Vector3::zeros(),
+            mass: 20.0,
+            stage_idx: 0,
+            stage_ignition_time: 0.0,
+        }
+    }
+
+    #[test]
+    fn with_law_flies_supplied_direction() {
+        let mission = Mission { name: "T".into(), stages: vec![] };
+        let mut ctrl = TvcController::with_law(Box::new(ConstantDirection::new(Vector3::new(0.0, 0.0, 1.0))));
+        let cmd = ctrl.update(&pad_state(), &mission, 0.01);
+        assert!((cmd.throttle - 1.0).abs() < 1e-9);
+        // The supplied direction matches the pad attitude, so no gimbal is needed.
+        assert!(cmd.gimbal_y.abs() < 1e-9 && cmd.gimbal_z.abs() < 1e-9);
+    }
+
+    #[test]
+    fn with_program_is_backward_compatible() {
+        let mission = Mission { name: "T".into(), stages: vec![] };
+        let mut ctrl = TvcController::with_program(PitchProgram::default());
+        let cmd = ctrl.update(&pad_state(), &mission, 0.01);
+        assert!(cmd.throttle > 0.0);
+    }
+
+    #[test]
+    fn accel_limit_throttles_down_a_high_twr_stage() {
+        use crate::vehicle::{Engine, Stage};
+
+        let mission = Mission {
+            name: "T".into(),
+            stages: vec![Stage {
+                name: "S".into(),
+                dry_mass: 10.0,
+                propellant_mass: 5.0,
+                engines: vec![Engine::new(10_000.0, 220.0, 1.0)], // huge TWR
+                cd: 0.3,
+                area: 0.01,
+                inertia: Vector3::new(5.0, 5.0, 0.5),
+                cp_offset: 0.3,
+                tvc_max: 0.1,
+            }],
+        };
+        let mut ctrl = TvcController::with_program(PitchProgram::default()).with_accel_limit(2.0);
+        let cmd = ctrl.update(&pad_state(), &mission, 0.01);
+        // pad_state's mass is 20.0 kg; full thrust would be 10_000/20 = 500 m/s^2 (~51 g).
+        assert!(cmd.throttle < 1.0, "a 51g-capable stage capped at 2g should throttle down");
+
+        let limited_accel = 10_000.0 * cmd.throttle / 20.0;
+        assert!(limited_accel <= 2.0 * G0 + 1e-6);
+    }
+
+    #[test]
+    fn accel_limit_falls_back_to_full_throttle_without_active_stage() {
+        let mission = Mission { name: "T".into(), stages: vec![] };
+        let mut ctrl = TvcController::with_program(PitchProgram::default()).with_accel_limit(50.0);
+        let cmd = ctrl.update(&pad_state(), &mission, 0.01);
+        // No active stage (empty mission) -> nothing to limit, falls back to 1.0.
+        assert!((cmd.throttle - 1.0).abs() < 1e-9);
+    }
+}
@@ -59,6 +59,7 @@ mod tests {
                 omega: Vector3::zeros(),
                 mass: 100.0,
                 stage_idx: 0,
+                stage_ignition_time: 0.0,
             },
             State {
                 time: 0.005,
@@ -68,6 +69,7 @@ mod tests {
                 omega: Vector3::zeros(),
                 mass: 99.5,
                 stage_idx: 0,
+                stage_ignition_time: 0.0,
             },
         ];
 
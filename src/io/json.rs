@@ -65,11 +65,167 @@ impl FlightSummary {
     }
 }
 
-/// Write flight summary as JSON to a writer.
+// ---------------------------------------------------------------------------
+// Flight-event detection
+// ---------------------------------------------------------------------------
+//
+// `FlightSummary` only keeps scalar extremes (apogee, max speed, ...). This
+// scans the full trajectory for discrete, named moments a post-processing
+// tool would want to key off directly instead of re-deriving from the raw
+// state history.
+
+/// A physically meaningful moment in a flight, identified by
+/// [`detect_events`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlightEventKind {
+    Liftoff,
+    MaxQ,
+    StageSeparation { from_stage: usize, to_stage: usize },
+    Burnout { stage: usize },
+    Apogee,
+    Impact,
+}
+
+/// One detected event: what happened, when, and the flight conditions at
+/// that instant.
+#[derive(Debug, Clone, Copy)]
+pub struct FlightEvent {
+    pub kind: FlightEventKind,
+    pub time: f64,
+    pub altitude: f64,
+    pub speed: f64,
+    pub mach: f64,
+    pub dynamic_pressure: f64,
+}
+
+fn event_at(state: &State, kind: FlightEventKind) -> FlightEvent {
+    let altitude = state.pos.z.max(0.0);
+    let speed = state.vel.norm();
+    let atm = atmosphere::isa(altitude);
+    FlightEvent {
+        kind,
+        time: state.time,
+        altitude,
+        speed,
+        mach: speed / atm.sound_speed,
+        dynamic_pressure: 0.5 * atm.density * speed * speed,
+    }
+}
+
+/// Scan `trajectory` for liftoff, max-Q, each stage separation, burnout per
+/// stage, apogee, and impact, returned in the order they occur in the
+/// trajectory.
+pub fn detect_events(trajectory: &[State]) -> Vec<FlightEvent> {
+    let mut events = Vec::new();
+    if trajectory.is_empty() {
+        return events;
+    }
+
+    if let Some(s) = trajectory.iter().find(|s| s.vel.z > 0.0) {
+        events.push(event_at(s, FlightEventKind::Liftoff));
+    }
+
+    let max_q_state = trajectory
+        .iter()
+        .max_by(|a, b| {
+            let qa = 0.5 * atmosphere::isa(a.pos.z.max(0.0)).density * a.vel.norm().powi(2);
+            let qb = 0.5 * atmosphere::isa(b.pos.z.max(0.0)).density * b.vel.norm().powi(2);
+            qa.partial_cmp(&qb).unwrap()
+        })
+        .unwrap();
+    events.push(event_at(max_q_state, FlightEventKind::MaxQ));
+
+    for w in trajectory.windows(2) {
+        if w[1].stage_idx != w[0].stage_idx {
+            events.push(event_at(&w[1], FlightEventKind::StageSeparation {
+                from_stage: w[0].stage_idx,
+                to_stage: w[1].stage_idx,
+            }));
+        }
+    }
+
+    events.extend(burnout_events(trajectory));
+
+    let apogee_state = trajectory
+        .iter()
+        .max_by(|a, b| a.pos.z.partial_cmp(&b.pos.z).unwrap())
+        .unwrap();
+    events.push(event_at(apogee_state, FlightEventKind::Apogee));
+
+    let last = trajectory.last().unwrap();
+    if last.pos.z <= 0.0 && trajectory.len() > 1 {
+        events.push(event_at(last, FlightEventKind::Impact));
+    }
+
+    events.sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap());
+    events
+}
+
+/// At most one burnout event per contiguous run of matching `stage_idx`,
+/// emitted only if that segment shows a mass-flow transition from burning to
+/// coasting (rather than one event per `stage_idx` value, in case a stage
+/// reignites — future-proofing aside, today's missions never do).
+fn burnout_events(trajectory: &[State]) -> Vec<FlightEvent> {
+    let mut events = Vec::new();
+    let mut seg_start = 0usize;
+    for i in 1..=trajectory.len() {
+        let boundary = i == trajectory.len() || trajectory[i].stage_idx != trajectory[seg_start].stage_idx;
+        if boundary {
+            if let Some(evt) = burnout_in_segment(&trajectory[seg_start..i], trajectory[seg_start].stage_idx) {
+                events.push(evt);
+            }
+            seg_start = i;
+        }
+    }
+    events
+}
+
+/// Finds the first point in `segment` where the mass-flow rate drops to a
+/// small fraction of its initial (burning) rate, i.e. engine cutoff.
+/// Returns `None` if the segment never burns propellant at all.
+fn burnout_in_segment(segment: &[State], stage: usize) -> Option<FlightEvent> {
+    if segment.len() < 2 {
+        return None;
+    }
+    let dt0 = segment[1].time - segment[0].time;
+    if dt0 <= 0.0 {
+        return None;
+    }
+    let initial_rate = (segment[0].mass - segment[1].mass) / dt0;
+    if initial_rate.abs() < 1e-6 {
+        return None;
+    }
+
+    for w in segment.windows(2) {
+        let dt = w[1].time - w[0].time;
+        if dt <= 0.0 {
+            continue;
+        }
+        let rate = (w[0].mass - w[1].mass) / dt;
+        if rate.abs() < 0.05 * initial_rate.abs() {
+            return Some(event_at(&w[1], FlightEventKind::Burnout { stage }));
+        }
+    }
+    None
+}
+
+/// Write flight summary as JSON to a writer. The `events` array is written
+/// empty; [`write_summary_with_events`] fills it with a detected-event timeline.
 pub fn write_summary<W: Write>(
     writer: &mut W,
     mission: &Mission,
     summary: &FlightSummary,
+) -> io::Result<()> {
+    write_summary_with_events(writer, mission, summary, &[])
+}
+
+/// Same as [`write_summary`] but with an explicit `events` array (normally
+/// produced by [`detect_events`]) appended to the JSON output.
+pub fn write_summary_with_events<W: Write>(
+    writer: &mut W,
+    mission: &Mission,
+    summary: &FlightSummary,
+    events: &[FlightEvent],
 ) -> io::Result<()> {
     writeln!(writer, "{{")?;
     writeln!(writer, "  \"mission\": {{")?;
@@ -85,7 +241,29 @@ pub fn write_summary<W: Write>(
     writeln!(writer, "    \"max_accel_g\": {:.2},", summary.max_accel_g)?;
     writeln!(writer, "    \"flight_time_s\": {:.2},", summary.flight_time)?;
     writeln!(writer, "    \"impact_speed_ms\": {:.2}", summary.impact_speed)?;
-    writeln!(writer, "  }}")?;
+    writeln!(writer, "  }},")?;
+    writeln!(writer, "  \"events\": [")?;
+    for (i, e) in events.iter().enumerate() {
+        let name = match e.kind {
+            FlightEventKind::Liftoff => "liftoff".to_string(),
+            FlightEventKind::MaxQ => "max_q".to_string(),
+            FlightEventKind::StageSeparation { from_stage, to_stage } => {
+                format!("stage_separation_{}_to_{}", from_stage, to_stage)
+            }
+            FlightEventKind::Burnout { stage } => format!("burnout_stage_{}", stage),
+            FlightEventKind::Apogee => "apogee".to_string(),
+            FlightEventKind::Impact => "impact".to_string(),
+        };
+        writeln!(writer, "    {{")?;
+        writeln!(writer, "      \"name\": \"{}\",", name)?;
+        writeln!(writer, "      \"time_s\": {:.3},", e.time)?;
+        writeln!(writer, "      \"altitude_m\": {:.2},", e.altitude)?;
+        writeln!(writer, "      \"speed_ms\": {:.2},", e.speed)?;
+        writeln!(writer, "      \"mach\": {:.3},", e.mach)?;
+        writeln!(writer, "      \"dynamic_pressure_pa\": {:.2}", e.dynamic_pressure)?;
+        writeln!(writer, "    }}{}", if i + 1 < events.len() { "," } else { "" })?;
+    }
+    writeln!(writer, "  ]")?;
     writeln!(writer, "}}")?;
     Ok(())
 }
@@ -115,6 +293,7 @@ mod tests {
                 omega: Vector3::zeros(),
                 mass: 100.0,
                 stage_idx: 0,
+                stage_ignition_time: 0.0,
             },
             State {
                 time: 10.0,
@@ -124,6 +303,7 @@ mod tests {
                 omega: Vector3::zeros(),
                 mass: 80.0,
                 stage_idx: 0,
+                stage_ignition_time: 0.0,
             },
             State {
                 time: 20.0,
@@ -133,6 +313,7 @@ mod tests {
                 omega: Vector3::zeros(),
                 mass: 80.0,
                 stage_idx: 0,
+                stage_ignition_time: 0.0,
             },
         ]
     }
@@ -160,5 +341,58 @@ mod tests {
         assert!(json.contains("\"mission\""));
         assert!(json.contains("\"apogee_m\""));
         assert!(json.contains("\"Test\""));
+        assert!(json.contains("\"events\""));
+    }
+
+    fn staged_trajectory() -> Vec<State> {
+        let state = |t: f64, z: f64, vz: f64, mass: f64, stage: usize| State {
+            time: t,
+            pos: Vector3::new(0.0, 0.0, z),
+            vel: Vector3::new(0.0, 0.0, vz),
+            quat: UnitQuaternion::identity(),
+            omega: Vector3::zeros(),
+            mass,
+            stage_idx: stage,
+            stage_ignition_time: 0.0,
+        };
+        vec![
+            state(0.0, 0.0, 0.0, 100.0, 0),
+            state(1.0, 50.0, 100.0, 80.0, 0),
+            state(2.0, 200.0, 150.0, 60.0, 0),
+            state(3.0, 400.0, 150.0, 60.0, 0), // stage 0 burned out: mass flat
+            state(4.0, 600.0, 120.0, 50.0, 1), // separation into stage 1
+            state(5.0, 700.0, 60.0, 40.0, 1),
+            state(6.0, 720.0, 0.0, 40.0, 1), // apogee
+            state(7.0, 600.0, -60.0, 40.0, 1),
+            state(8.0, 0.0, -100.0, 40.0, 1), // impact
+        ]
+    }
+
+    #[test]
+    fn detects_liftoff_apogee_and_impact() {
+        let traj = staged_trajectory();
+        let events = detect_events(&traj);
+
+        assert!(events.iter().any(|e| e.kind == FlightEventKind::Liftoff));
+        assert!(events.iter().any(|e| e.kind == FlightEventKind::Apogee && (e.altitude - 720.0).abs() < 1e-6));
+        assert!(events.iter().any(|e| e.kind == FlightEventKind::Impact));
+    }
+
+    #[test]
+    fn detects_stage_separation_and_burnout() {
+        let traj = staged_trajectory();
+        let events = detect_events(&traj);
+
+        assert!(events.iter().any(|e| e.kind == FlightEventKind::StageSeparation { from_stage: 0, to_stage: 1 }));
+        assert!(events.iter().any(|e| e.kind == FlightEventKind::Burnout { stage: 0 }));
+    }
+
+    #[test]
+    fn events_are_sorted_by_time() {
+        let traj = staged_trajectory();
+        let events = detect_events(&traj);
+        for w in events.windows(2) {
+            assert!(w[0].time <= w[1].time);
+        }
     }
 }
@@ -5,6 +5,7 @@ mod gnc_mod;
 pub mod sim;
 pub mod io;
 pub mod orbital;
+pub mod rng;
 
 // The gnc module: expose gnc_mod as `gnc` publicly
 pub mod gnc {
@@ -17,12 +18,13 @@ pub mod atmosphere {
 }
 
 pub mod integrator {
-    pub use crate::sim::runner::{simulate, simulate_with};
-    pub use crate::sim::integrator::rk4_step;
+    pub use crate::sim::runner::{simulate, simulate_with, simulate_with_adaptive, simulate_with_field, simulate_with_launch, RailDeparture};
+    pub use crate::sim::integrator::{rk4_step, rk4_step_with_field, rk4_step_with_wind, rkf45_step};
 }
 
 pub mod types {
-    pub use crate::dynamics::state::{Deriv, GncCommand, SimConfig, State, G0, EARTH_RADIUS};
+    pub use crate::dynamics::state::{AdaptiveConfig, Deriv, GncCommand, LaunchConfig, SimConfig, State, WindModel, G0, EARTH_RADIUS};
+    pub use crate::dynamics::turbulence::{DrydenField, SteadyWind, TurbulenceConfig, WindField};
     pub use crate::vehicle::stage::Stage;
     pub use crate::vehicle::mission::Mission;
 }
@@ -43,7 +43,9 @@ fn main() {
         let json_path = "flight_summary.json";
         rocket_sim::io::csv::write_trajectory_file(csv_path, &trajectory)
             .expect("Failed to write CSV");
-        rocket_sim::io::json::write_summary_file(json_path, &mission, &summary)
+        let events = rocket_sim::io::json::detect_events(&trajectory);
+        let mut json_file = std::fs::File::create(json_path).expect("Failed to create JSON file");
+        rocket_sim::io::json::write_summary_with_events(&mut json_file, &mission, &summary, &events)
             .expect("Failed to write JSON");
         println!("Exported: {} and {}", csv_path, json_path);
     }
@@ -64,8 +66,8 @@ fn main() {
             "  Mass: {:.0}+{:.0} kg  Thrust: {:.0} N  Isp: {:.0} s  Burn: {:.1} s",
             stage.dry_mass,
             stage.propellant_mass,
-            stage.thrust,
-            stage.isp,
+            stage.thrust(),
+            stage.isp(),
             stage.burn_time()
         );
         println!(
@@ -149,6 +149,172 @@ impl KeplerianElements {
             true_anom: 0.0,
         }
     }
+
+    // -----------------------------------------------------------------------
+    // Derived orbital geometry
+    // -----------------------------------------------------------------------
+    //
+    // Commonly needed quantities that would otherwise make callers bounce
+    // through `to_state_vector`/`from_state_vector` just to re-derive them.
+
+    /// Apoapsis radius, `a(1+e)`.
+    pub fn apoapsis_radius(&self) -> f64 {
+        self.sma * (1.0 + self.ecc)
+    }
+
+    /// Periapsis radius, `a(1-e)`.
+    pub fn periapsis_radius(&self) -> f64 {
+        self.sma * (1.0 - self.ecc)
+    }
+
+    /// Specific orbital energy, `-mu/(2a)`.
+    pub fn specific_energy_mu(&self, mu: f64) -> f64 {
+        -mu / (2.0 * self.sma)
+    }
+
+    /// Same as [`KeplerianElements::specific_energy_mu`], using `MU_EARTH`.
+    pub fn specific_energy(&self) -> f64 {
+        self.specific_energy_mu(MU_EARTH)
+    }
+
+    /// Orbital radius at a given true anomaly `ν`, `p/(1 + e cos ν)` with
+    /// semi-latus rectum `p = a(1-e^2)`.
+    pub fn radius_at_true_anomaly(&self, nu: f64) -> f64 {
+        let p = self.sma * (1.0 - self.ecc * self.ecc);
+        p / (1.0 + self.ecc * nu.cos())
+    }
+
+    /// Orbital speed at a given true anomaly `ν`, via the vis-viva equation
+    /// evaluated at [`KeplerianElements::radius_at_true_anomaly`].
+    pub fn speed_at_true_anomaly_mu(&self, nu: f64, mu: f64) -> f64 {
+        let r = self.radius_at_true_anomaly(nu);
+        (mu * (2.0 / r - 1.0 / self.sma)).sqrt()
+    }
+
+    /// Same as [`KeplerianElements::speed_at_true_anomaly_mu`], using `MU_EARTH`.
+    pub fn speed_at_true_anomaly(&self, nu: f64) -> f64 {
+        self.speed_at_true_anomaly_mu(nu, MU_EARTH)
+    }
+
+    /// Flight path angle at the current true anomaly: the angle between the
+    /// velocity vector and the local horizontal, `atan2(e sin ν, 1 + e cos ν)`.
+    pub fn flight_path_angle(&self) -> f64 {
+        (self.ecc * self.true_anom.sin()).atan2(1.0 + self.ecc * self.true_anom.cos())
+    }
+
+    // -----------------------------------------------------------------------
+    // Anomaly conversions and two-body time propagation
+    // -----------------------------------------------------------------------
+
+    /// Eccentric anomaly `E` corresponding to this orbit's current true
+    /// anomaly `ν`, via `tan(E/2) = sqrt((1-e)/(1+e)) tan(ν/2)` (evaluated
+    /// with `atan2` on the half-angle sine/cosine instead of `tan` directly,
+    /// so it stays well-behaved near `ν = π`).
+    pub fn eccentric_anomaly(&self) -> f64 {
+        let half = self.true_anom / 2.0;
+        let y = (1.0 - self.ecc).sqrt() * half.sin();
+        let x = (1.0 + self.ecc).sqrt() * half.cos();
+        normalize_angle(2.0 * y.atan2(x))
+    }
+
+    /// Mean anomaly `M = E - e sin E`, derived from the current true anomaly.
+    pub fn mean_anomaly(&self) -> f64 {
+        let e_anom = self.eccentric_anomaly();
+        normalize_angle(e_anom - self.ecc * e_anom.sin())
+    }
+
+    /// Solve Kepler's equation `M = E - e sin E` for the eccentric anomaly
+    /// `E` via Newton-Raphson: `E_{k+1} = E_k - (E_k - e sin E_k - M) / (1 - e cos E_k)`.
+    /// Starts from `E0 = M`, or `M + e*sign(sin M)` for high eccentricity
+    /// (the textbook starting guess that keeps convergence fast near e→1),
+    /// iterating to ~1e-12 with a cap of 50 steps.
+    pub fn solve_kepler_equation(mean_anomaly: f64, ecc: f64) -> f64 {
+        let m = normalize_angle(mean_anomaly);
+        let mut e_anom = if ecc > 0.8 {
+            m + ecc * m.sin().signum()
+        } else {
+            m
+        };
+        for _ in 0..50 {
+            let f = e_anom - ecc * e_anom.sin() - m;
+            let f_prime = 1.0 - ecc * e_anom.cos();
+            let delta = f / f_prime;
+            e_anom -= delta;
+            if delta.abs() < 1e-12 {
+                break;
+            }
+        }
+        e_anom
+    }
+
+    /// True anomaly `ν` corresponding to an eccentric anomaly `E`, the
+    /// inverse of [`KeplerianElements::eccentric_anomaly`].
+    fn true_anomaly_from_eccentric(e_anom: f64, ecc: f64) -> f64 {
+        let half = e_anom / 2.0;
+        let y = (1.0 + ecc).sqrt() * half.sin();
+        let x = (1.0 - ecc).sqrt() * half.cos();
+        normalize_angle(2.0 * y.atan2(x))
+    }
+
+    /// Advance this unperturbed two-body orbit by `dt` seconds, returning a
+    /// new [`KeplerianElements`] with only `true_anom` changed: convert the
+    /// current true anomaly to mean anomaly, advance it by `n*dt` with mean
+    /// motion `n = sqrt(mu/a^3)`, solve Kepler's equation for the new
+    /// eccentric anomaly, then map back to true anomaly.
+    pub fn propagate_mu(&self, dt: f64, mu: f64) -> Self {
+        let n = (mu / self.sma.powi(3)).sqrt();
+        let m = self.mean_anomaly() + n * dt;
+        let e_anom = Self::solve_kepler_equation(m, self.ecc);
+        let true_anom = Self::true_anomaly_from_eccentric(e_anom, self.ecc);
+        Self { true_anom, ..*self }
+    }
+
+    /// Same as [`KeplerianElements::propagate_mu`], using Earth's `MU_EARTH`.
+    pub fn propagate(&self, dt: f64) -> Self {
+        self.propagate_mu(dt, MU_EARTH)
+    }
+}
+
+/// Wrap an angle into `[0, 2π)`.
+fn normalize_angle(angle: f64) -> f64 {
+    let two_pi = 2.0 * std::f64::consts::PI;
+    let a = angle % two_pi;
+    if a < 0.0 { a + two_pi } else { a }
+}
+
+// ---------------------------------------------------------------------------
+// Report-friendly orbital elements (adds apsis altitudes to the raw Keplerian set)
+// ---------------------------------------------------------------------------
+
+/// [`KeplerianElements`] plus apoapsis/periapsis altitude above the surface —
+/// the shape users actually want to print after an ascent-to-orbit run.
+#[derive(Debug, Clone, Copy)]
+pub struct OrbitalElements {
+    pub sma: f64,
+    pub ecc: f64,
+    pub inc: f64,
+    pub raan: f64,
+    pub argp: f64,
+    pub true_anomaly: f64,
+    pub apoapsis_alt: f64,
+    pub periapsis_alt: f64,
+}
+
+impl OrbitalElements {
+    /// Derive from an ECI state vector via [`KeplerianElements::from_state_vector`].
+    pub fn from_state_vector(pos: &Vector3<f64>, vel: &Vector3<f64>) -> Self {
+        let k = KeplerianElements::from_state_vector(pos, vel);
+        Self {
+            sma: k.sma,
+            ecc: k.ecc,
+            inc: k.inc,
+            raan: k.raan,
+            argp: k.argp,
+            true_anomaly: k.true_anom,
+            apoapsis_alt: k.sma * (1.0 + k.ecc) - R_EARTH_ECI,
+            periapsis_alt: k.sma * (1.0 - k.ecc) - R_EARTH_ECI,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -181,4 +347,148 @@ mod tests {
         // ISS period ~92 min = ~5540 s
         assert!(period > 5400.0 && period < 5700.0, "LEO period should be ~92 min, got {:.0} s", period);
     }
+
+    #[test]
+    fn orbital_elements_circular_apsides_match_altitude() {
+        let alt = 400_000.0;
+        let orbit = KeplerianElements::circular(alt, 0.0);
+        let (pos, vel) = orbit.to_state_vector();
+        let elements = OrbitalElements::from_state_vector(&pos, &vel);
+        assert!((elements.apoapsis_alt - alt).abs() < 10.0);
+        assert!((elements.periapsis_alt - alt).abs() < 10.0);
+    }
+
+    #[test]
+    fn kepler_equation_solver_round_trips_eccentric_anomaly() {
+        for &e in &[0.0, 0.1, 0.5, 0.9] {
+            for &ea in &[0.2, 1.5, 3.0, 5.5] {
+                let m = ea - e * ea.sin();
+                let solved = KeplerianElements::solve_kepler_equation(m, e);
+                assert!(
+                    (solved - ea).abs() < 1e-9,
+                    "ecc={e}, expected E={ea}, got {solved}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn eccentric_and_mean_anomaly_agree_at_periapsis_and_apoapsis() {
+        let mut orbit = KeplerianElements::circular(400_000.0, 0.0);
+        orbit.ecc = 0.3;
+
+        orbit.true_anom = 0.0; // periapsis
+        assert!(orbit.eccentric_anomaly().abs() < 1e-9);
+        assert!(orbit.mean_anomaly().abs() < 1e-9);
+
+        orbit.true_anom = std::f64::consts::PI; // apoapsis
+        assert!((orbit.eccentric_anomaly() - std::f64::consts::PI).abs() < 1e-9);
+        assert!((orbit.mean_anomaly() - std::f64::consts::PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn propagate_circular_orbit_one_period_returns_to_start() {
+        let orbit = KeplerianElements::circular(400_000.0, 51.6_f64.to_radians());
+        let period = orbit.period();
+        let advanced = orbit.propagate(period);
+        assert!((advanced.true_anom - orbit.true_anom).abs() < 1e-6 || (advanced.true_anom - 2.0 * std::f64::consts::PI).abs() < 1e-6);
+        assert!((advanced.sma - orbit.sma).abs() < 1e-6, "propagate should only change true_anom");
+        assert!((advanced.ecc - orbit.ecc).abs() < 1e-12);
+    }
+
+    #[test]
+    fn propagate_quarter_period_matches_numerical_integration() {
+        use crate::orbital::propagator::{propagate_orbit, OrbitalState};
+
+        let orbit = KeplerianElements::circular(400_000.0, 0.0);
+        let quarter = orbit.period() / 4.0;
+        let analytic = orbit.propagate(quarter);
+        let (analytic_pos, _) = analytic.to_state_vector();
+
+        let (pos0, vel0) = orbit.to_state_vector();
+        let initial = OrbitalState { time: 0.0, pos: pos0, vel: vel0 };
+        let traj = propagate_orbit(&initial, 1.0, quarter, false, None);
+        let numeric_pos = traj.last().unwrap().pos;
+
+        let diff = (analytic_pos - numeric_pos).norm();
+        assert!(diff < 100.0, "Kepler propagation should match RK4 two-body integration closely, got {:.2} m", diff);
+    }
+
+    #[test]
+    fn apsis_radii_match_circular_orbit() {
+        let alt = 400_000.0;
+        let orbit = KeplerianElements::circular(alt, 0.0);
+        assert!((orbit.apoapsis_radius() - orbit.sma).abs() < 1e-6);
+        assert!((orbit.periapsis_radius() - orbit.sma).abs() < 1e-6);
+    }
+
+    #[test]
+    fn apsis_radii_bracket_sma_for_eccentric_orbit() {
+        let mut orbit = KeplerianElements::circular(400_000.0, 0.0);
+        orbit.ecc = 0.3;
+        assert!(orbit.apoapsis_radius() > orbit.sma);
+        assert!(orbit.periapsis_radius() < orbit.sma);
+        assert!((orbit.apoapsis_radius() + orbit.periapsis_radius()) / 2.0 - orbit.sma < 1e-6);
+    }
+
+    #[test]
+    fn specific_energy_is_negative_for_bound_orbit() {
+        let orbit = KeplerianElements::circular(400_000.0, 0.0);
+        assert!(orbit.specific_energy() < 0.0);
+    }
+
+    #[test]
+    fn radius_and_speed_at_true_anomaly_match_circular_case() {
+        let r = R_EARTH_ECI + 400_000.0;
+        let orbit = KeplerianElements::circular(400_000.0, 0.0);
+        for &nu in &[0.0, 1.0, 3.0, 5.0] {
+            assert!((orbit.radius_at_true_anomaly(nu) - r).abs() < 1.0);
+            let v_circ = (MU_EARTH / r).sqrt();
+            assert!((orbit.speed_at_true_anomaly(nu) - v_circ).abs() < 1.0);
+        }
+    }
+
+    #[test]
+    fn radius_at_true_anomaly_matches_periapsis_and_apoapsis() {
+        let mut orbit = KeplerianElements::circular(400_000.0, 0.0);
+        orbit.ecc = 0.2;
+        assert!((orbit.radius_at_true_anomaly(0.0) - orbit.periapsis_radius()).abs() < 1e-3);
+        assert!((orbit.radius_at_true_anomaly(std::f64::consts::PI) - orbit.apoapsis_radius()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn flight_path_angle_is_zero_for_circular_orbit() {
+        let mut orbit = KeplerianElements::circular(400_000.0, 0.0);
+        orbit.true_anom = 1.2;
+        assert!(orbit.flight_path_angle().abs() < 1e-12);
+    }
+
+    #[test]
+    fn flight_path_angle_is_zero_at_apsides_for_eccentric_orbit() {
+        let mut orbit = KeplerianElements::circular(400_000.0, 0.0);
+        orbit.ecc = 0.4;
+
+        orbit.true_anom = 0.0;
+        assert!(orbit.flight_path_angle().abs() < 1e-9);
+        orbit.true_anom = std::f64::consts::PI;
+        assert!(orbit.flight_path_angle().abs() < 1e-9);
+
+        orbit.true_anom = std::f64::consts::FRAC_PI_2;
+        assert!(orbit.flight_path_angle() > 0.0, "climbing through the ascending side should have a positive FPA");
+    }
+
+    #[test]
+    fn propagate_eccentric_orbit_conserves_sma_and_ecc() {
+        let mut orbit = KeplerianElements::circular(400_000.0, 0.0);
+        orbit.ecc = 0.4;
+        orbit.true_anom = 0.7;
+
+        let advanced = orbit.propagate(123.4);
+        assert!((advanced.sma - orbit.sma).abs() < 1e-6);
+        assert!((advanced.ecc - orbit.ecc).abs() < 1e-12);
+        assert!((advanced.inc - orbit.inc).abs() < 1e-12);
+        assert!((advanced.raan - orbit.raan).abs() < 1e-12);
+        assert!((advanced.argp - orbit.argp).abs() < 1e-12);
+        assert_ne!(advanced.true_anom, orbit.true_anom);
+    }
 }
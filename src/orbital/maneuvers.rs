@@ -1,4 +1,9 @@
-use crate::physics::gravity::MU_EARTH;
+use nalgebra::Vector3;
+
+use crate::dynamics::state::G0;
+use crate::physics::gravity::{gravity_j2_eci, gravity_pointmass_eci, MU_EARTH};
+use crate::vehicle::Stage;
+use super::propagator::{rk4_orbital_step, OrbitalState};
 
 /// Result of a Hohmann transfer calculation.
 #[derive(Debug, Clone, Copy)]
@@ -52,6 +57,303 @@ pub fn circular_velocity_mu(r: f64, mu: f64) -> f64 {
     (mu / r).sqrt()
 }
 
+// ---------------------------------------------------------------------------
+// Bi-elliptic transfer and plane changes
+// ---------------------------------------------------------------------------
+//
+// `hohmann` is the cheapest two-impulse coplanar transfer when r2/r1 isn't
+// too large, but for big radius ratios a three-burn bi-elliptic transfer
+// through a high intermediate apoapsis can cost less total delta-v (at the
+// price of transfer time) — a standard mission-design trade this module
+// couldn't express before.
+
+/// Result of a bi-elliptic transfer calculation.
+#[derive(Debug, Clone, Copy)]
+pub struct BiellipticTransfer {
+    pub dv1: f64,           // m/s, raise apoapsis from r1 to rb
+    pub dv2: f64,           // m/s, shift periapsis from r1 to r2 at rb
+    pub dv3: f64,           // m/s, circularize at r2
+    pub total_dv: f64,      // m/s
+    pub transfer_time: f64, // s, sum of the two transfer-ellipse half-periods
+    pub r1: f64,
+    pub r2: f64,
+    pub rb: f64,
+}
+
+/// Compute a bi-elliptic transfer between two circular orbits through an
+/// intermediate apoapsis `rb` (`rb > r1`, `rb > r2`).
+pub fn bielliptic(r1: f64, r2: f64, rb: f64) -> BiellipticTransfer {
+    bielliptic_mu(r1, r2, rb, MU_EARTH)
+}
+
+pub fn bielliptic_mu(r1: f64, r2: f64, rb: f64, mu: f64) -> BiellipticTransfer {
+    let v_circ1 = (mu / r1).sqrt();
+    let v_circ2 = (mu / r2).sqrt();
+
+    // Burn 1: r1 circular -> transfer ellipse 1 (periapsis r1, apoapsis rb)
+    let a1 = (r1 + rb) / 2.0;
+    let v1_peri = (mu * (2.0 / r1 - 1.0 / a1)).sqrt();
+    let dv1 = (v1_peri - v_circ1).abs();
+
+    // Burn 2, at rb: transfer ellipse 1 -> transfer ellipse 2 (periapsis r2, apoapsis rb)
+    let a2 = (rb + r2) / 2.0;
+    let v1_apo = (mu * (2.0 / rb - 1.0 / a1)).sqrt();
+    let v2_apo = (mu * (2.0 / rb - 1.0 / a2)).sqrt();
+    let dv2 = (v2_apo - v1_apo).abs();
+
+    // Burn 3, at r2: transfer ellipse 2 -> r2 circular
+    let v2_peri = (mu * (2.0 / r2 - 1.0 / a2)).sqrt();
+    let dv3 = (v_circ2 - v2_peri).abs();
+
+    let t1 = std::f64::consts::PI * (a1.powi(3) / mu).sqrt();
+    let t2 = std::f64::consts::PI * (a2.powi(3) / mu).sqrt();
+
+    BiellipticTransfer {
+        dv1,
+        dv2,
+        dv3,
+        total_dv: dv1 + dv2 + dv3,
+        transfer_time: t1 + t2,
+        r1,
+        r2,
+        rb,
+    }
+}
+
+/// Delta-v for a pure plane change of `delta_i` at circular radius `r`:
+/// `Δv = 2 v_circ sin(Δi/2)`.
+pub fn inclination_change(r: f64, delta_i: f64) -> f64 {
+    inclination_change_mu(r, delta_i, MU_EARTH)
+}
+
+pub fn inclination_change_mu(r: f64, delta_i: f64, mu: f64) -> f64 {
+    2.0 * circular_velocity_mu(r, mu) * (delta_i / 2.0).sin()
+}
+
+/// Result of a Hohmann transfer with the plane change merged into the
+/// apoapsis circularization burn.
+#[derive(Debug, Clone, Copy)]
+pub struct CombinedPlaneChangeTransfer {
+    pub dv1: f64,      // m/s, raise apoapsis (no plane change, cheapest done at perigee)
+    pub dv2: f64,      // m/s, circularize + full plane change combined
+    pub total_dv: f64, // m/s
+    pub transfer_time: f64,
+    pub delta_i: f64,
+}
+
+/// Hohmann transfer from `r1` to `r2` with a `delta_i` plane change folded
+/// into the second (circularizing) burn via the law of cosines:
+/// `Δv2 = sqrt(v_a^2 + v_c2^2 - 2 v_a v_c2 cos(Δi))`. Cheaper than doing the
+/// plane change as a separate burn, since it's combined at the lower-speed
+/// apoapsis rather than executed independently.
+pub fn combined_plane_change(r1: f64, r2: f64, delta_i: f64) -> CombinedPlaneChangeTransfer {
+    combined_plane_change_mu(r1, r2, delta_i, MU_EARTH)
+}
+
+pub fn combined_plane_change_mu(r1: f64, r2: f64, delta_i: f64, mu: f64) -> CombinedPlaneChangeTransfer {
+    let a_transfer = (r1 + r2) / 2.0;
+    let v_circ1 = (mu / r1).sqrt();
+    let v_circ2 = (mu / r2).sqrt();
+    let v_transfer_1 = (mu * (2.0 / r1 - 1.0 / a_transfer)).sqrt();
+    let v_apo = (mu * (2.0 / r2 - 1.0 / a_transfer)).sqrt();
+
+    let dv1 = (v_transfer_1 - v_circ1).abs();
+    let dv2 = (v_apo * v_apo + v_circ2 * v_circ2 - 2.0 * v_apo * v_circ2 * delta_i.cos()).sqrt();
+
+    let transfer_time = std::f64::consts::PI * (a_transfer.powi(3) / mu).sqrt();
+
+    CombinedPlaneChangeTransfer {
+        dv1,
+        dv2,
+        total_dv: dv1 + dv2,
+        transfer_time,
+        delta_i,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Edelbaum continuous low-thrust transfer estimator
+// ---------------------------------------------------------------------------
+//
+// The impulsive transfers above assume the burn is instantaneous, which
+// electric propulsion can't do — an ion thruster spirals up over days or
+// months. Edelbaum's formula gives the first-order delta-v for a
+// quasi-circular continuous-thrust transfer combining a radius change and a
+// plane change, so low-thrust mission sizing can be budgeted the same way
+// the impulsive transfers budget chemical burns.
+
+/// Result of an Edelbaum-style continuous low-thrust transfer estimate.
+#[derive(Debug, Clone, Copy)]
+pub struct EdelbaumTransfer {
+    pub delta_v: f64,         // m/s
+    pub transfer_time: f64,   // s, constant-acceleration time to deliver delta_v
+    pub burn_time: f64,       // s, the engine fires continuously, so == transfer_time
+    pub propellant_used: f64, // kg, via the rocket equation
+}
+
+/// Edelbaum's combined eccentricity/inclination-change delta-v for a
+/// quasi-circular continuous-thrust transfer between circular speeds `v0`
+/// and `vf` (`v0 = sqrt(mu/r0)`, `vf = sqrt(mu/rf)`), folding in a total
+/// plane change `delta_i` (rad):
+/// `Δv = sqrt(v0^2 - 2 v0 vf cos(π/2 Δi) + vf^2)`.
+pub fn edelbaum_delta_v(v0: f64, vf: f64, delta_i: f64) -> f64 {
+    (v0 * v0 - 2.0 * v0 * vf * (std::f64::consts::FRAC_PI_2 * delta_i).cos() + vf * vf).sqrt()
+}
+
+/// Estimate a continuous low-thrust transfer's delta-v, burn time,
+/// propellant, and total transfer time, given constant `thrust`/`isp` and
+/// the wet `mass` at transfer start. Treats thrust acceleration as constant
+/// over the transfer, the standard first-order Edelbaum assumption.
+pub fn edelbaum_transfer(v0: f64, vf: f64, delta_i: f64, thrust: f64, isp: f64, mass: f64) -> EdelbaumTransfer {
+    let delta_v = edelbaum_delta_v(v0, vf, delta_i);
+    let ve = isp * G0;
+    let accel = thrust / mass;
+    let transfer_time = delta_v / accel;
+    let propellant_used = mass * (1.0 - (-delta_v / ve).exp());
+
+    EdelbaumTransfer {
+        delta_v,
+        transfer_time,
+        burn_time: transfer_time,
+        propellant_used,
+    }
+}
+
+/// Same as [`edelbaum_transfer`], taking a [`Stage`] so its thrust, Isp, and
+/// wet mass feed the estimate directly. This crate models vehicles as staged
+/// [`Stage`]s rather than a single aggregate "Vehicle" type, so `Stage` is
+/// the natural stand-in here.
+pub fn edelbaum_transfer_for_stage(v0: f64, vf: f64, delta_i: f64, stage: &Stage) -> EdelbaumTransfer {
+    edelbaum_transfer(v0, vf, delta_i, stage.thrust(), stage.isp(), stage.total_mass())
+}
+
+// ---------------------------------------------------------------------------
+// Finite-burn maneuver execution
+// ---------------------------------------------------------------------------
+//
+// `hohmann` above gives the impulsive delta-v for an instantaneous burn.
+// A real engine burns over a finite arc, so the vehicle's thrust direction
+// and gravity both rotate under it — `simulate_finite_burn` integrates that
+// arc on top of the same `rk4_orbital_step` stepper `propagate_orbit` uses,
+// so callers can compare the finite-burn result's gravity/steering losses
+// against the impulsive `HohmannTransfer` total_dv.
+
+/// Thrust-direction law for a finite burn, evaluated against the current
+/// `OrbitalState` at every integration step.
+#[derive(Debug, Clone, Copy)]
+pub enum ThrustSteering {
+    /// Prograde: along the current velocity vector.
+    AlongVelocity,
+    /// Retrograde: opposite the current velocity vector.
+    AntiVelocity,
+    /// A fixed direction in the ECI frame, e.g. for an inertially-held burn.
+    InertialFixed(Vector3<f64>),
+    /// A blend of the local tangential (velocity) and radial (position)
+    /// unit vectors, tipped by `pitch` radians from tangential toward
+    /// radial — `pitch = 0` is pure prograde, `pitch = PI/2` pure radial.
+    TangentialPlusRadial { pitch: f64 },
+}
+
+impl ThrustSteering {
+    fn direction(&self, state: &OrbitalState) -> Vector3<f64> {
+        match self {
+            ThrustSteering::AlongVelocity => state.vel.normalize(),
+            ThrustSteering::AntiVelocity => -state.vel.normalize(),
+            ThrustSteering::InertialFixed(dir) => dir.normalize(),
+            ThrustSteering::TangentialPlusRadial { pitch } => {
+                let tangential = state.vel.normalize();
+                let radial = state.pos.normalize();
+                (tangential * pitch.cos() + radial * pitch.sin()).normalize()
+            }
+        }
+    }
+}
+
+/// A finite-duration burn: constant thrust/Isp, steered by `steering`, that
+/// stops at whichever of `dv_budget` or `max_duration` comes first (or when
+/// propellant runs out, if `mass` is limiting).
+#[derive(Debug, Clone, Copy)]
+pub struct FiniteBurn {
+    pub thrust: f64,   // N
+    pub isp: f64,       // s
+    pub steering: ThrustSteering,
+    pub dv_budget: f64,    // m/s, stop once accumulated delta-v reaches this
+    pub max_duration: f64, // s, stop once burn time reaches this
+}
+
+/// Outcome of `simulate_finite_burn`.
+#[derive(Debug, Clone)]
+pub struct FiniteBurnResult {
+    pub trajectory: Vec<OrbitalState>,
+    pub delta_v: f64,
+    pub propellant_used: f64,
+    pub burn_time: f64,
+}
+
+/// Integrate a real thrust arc starting from `initial` with vehicle `mass`,
+/// sampling/stepping at `dt`. Gravity is J2 or point-mass per `use_j2`;
+/// thrust acceleration is `steering.direction(state) * thrust / remaining_mass`.
+/// Propellant consumption uses `ve = isp * G0`, `mass_flow = thrust / ve`, and
+/// each step's delta-v is accumulated via the rocket equation
+/// `ve * ln(m0 / m1)` (not `ve * dm / m0`), so the result is exact regardless
+/// of step size.
+pub fn simulate_finite_burn(
+    initial: &OrbitalState,
+    mass: f64,
+    burn: &FiniteBurn,
+    dt: f64,
+    use_j2: bool,
+) -> FiniteBurnResult {
+    let ve = burn.isp * G0;
+    let mass_flow = burn.thrust / ve;
+
+    let mut state = initial.clone();
+    let mut remaining_mass = mass;
+    let mut delta_v = 0.0;
+    let mut burn_time = 0.0;
+    let mut trajectory = vec![state.clone()];
+
+    while burn_time < burn.max_duration && delta_v < burn.dv_budget {
+        let mut step_dt = dt.min(burn.max_duration - burn_time);
+        let mut dm = (mass_flow * step_dt).min(remaining_mass - 1e-6);
+        if dm <= 0.0 {
+            break;
+        }
+        // Shorten a step that would carry delta-v past `dv_budget`, so the
+        // burn ends on the budget rather than up to one step beyond it.
+        let dm_to_budget = remaining_mass * (1.0 - (-(burn.dv_budget - delta_v) / ve).exp());
+        let reaches_budget = dm_to_budget <= dm;
+        if reaches_budget {
+            dm = dm_to_budget;
+            step_dt = dm / mass_flow;
+        }
+
+        let dir = burn.steering.direction(&state);
+        let thrust_mass = remaining_mass;
+        let accel_fn = move |pos: &Vector3<f64>, vel: &Vector3<f64>| -> Vector3<f64> {
+            let g = if use_j2 { gravity_j2_eci(pos) } else { gravity_pointmass_eci(pos) };
+            let _ = vel;
+            g + dir * (burn.thrust / thrust_mass)
+        };
+
+        state = rk4_orbital_step(&state, step_dt, &accel_fn);
+        trajectory.push(state.clone());
+
+        let m0 = remaining_mass;
+        let m1 = m0 - dm;
+        delta_v = if reaches_budget { burn.dv_budget } else { delta_v + ve * (m0 / m1).ln() };
+        remaining_mass = m1;
+        burn_time += step_dt;
+    }
+
+    FiniteBurnResult {
+        trajectory,
+        delta_v,
+        propellant_used: mass - remaining_mass,
+        burn_time,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,4 +379,179 @@ mod tests {
         let h = hohmann(r, r);
         assert!(h.total_dv < 1e-6);
     }
+
+    #[test]
+    fn bielliptic_beats_hohmann_for_large_radius_ratio() {
+        // Classic textbook regime (r2/r1 > ~11.9) where bi-elliptic wins.
+        let r1 = R_EARTH_ECI + 200_000.0;
+        let r2 = r1 * 15.0;
+        let rb = r2 * 3.0;
+
+        let h = hohmann(r1, r2);
+        let b = bielliptic(r1, r2, rb);
+
+        assert!(
+            b.total_dv < h.total_dv,
+            "bi-elliptic ({:.0} m/s) should beat Hohmann ({:.0} m/s) at this radius ratio",
+            b.total_dv, h.total_dv
+        );
+        assert!(b.transfer_time > h.transfer_time, "bi-elliptic trades time for delta-v");
+    }
+
+    #[test]
+    fn bielliptic_zero_when_rb_equals_r1_equals_r2() {
+        let r = R_EARTH_ECI + 400_000.0;
+        let b = bielliptic(r, r, r);
+        assert!(b.total_dv < 1e-6);
+    }
+
+    #[test]
+    fn inclination_change_scales_with_angle() {
+        let r = R_EARTH_ECI + 400_000.0;
+        let dv_small = inclination_change(r, 1.0_f64.to_radians());
+        let dv_large = inclination_change(r, 10.0_f64.to_radians());
+        assert!(dv_large > dv_small);
+        assert!(inclination_change(r, 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn combined_plane_change_costs_less_than_separate_burns() {
+        let r1 = R_EARTH_ECI + 200_000.0;
+        let r2 = 42_164_000.0;
+        let delta_i = 28.5_f64.to_radians(); // a typical launch-site-to-GEO inclination drop
+
+        let h = hohmann(r1, r2);
+        let separate_plane_change = inclination_change(r2, delta_i);
+        let separate_total = h.total_dv + separate_plane_change;
+
+        let combined = combined_plane_change(r1, r2, delta_i);
+        assert!(
+            combined.total_dv < separate_total,
+            "combining the plane change into the circularization burn should be cheaper: {:.0} vs {:.0} m/s",
+            combined.total_dv, separate_total
+        );
+        // With delta_i = 0 the combined burn should reduce exactly to plain Hohmann.
+        let no_plane_change = combined_plane_change(r1, r2, 0.0);
+        assert!((no_plane_change.total_dv - h.total_dv).abs() < 1e-6);
+    }
+
+    #[test]
+    fn edelbaum_zero_dv_for_matched_speeds_and_no_plane_change() {
+        let v = circular_velocity(R_EARTH_ECI + 400_000.0);
+        assert!(edelbaum_delta_v(v, v, 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn edelbaum_dv_increases_with_plane_change() {
+        let r1 = R_EARTH_ECI + 400_000.0;
+        let r2 = 42_164_000.0;
+        let v0 = circular_velocity(r1);
+        let vf = circular_velocity(r2);
+
+        let dv_no_plane = edelbaum_delta_v(v0, vf, 0.0);
+        let dv_with_plane = edelbaum_delta_v(v0, vf, 28.5_f64.to_radians());
+        assert!(dv_with_plane > dv_no_plane);
+    }
+
+    #[test]
+    fn edelbaum_transfer_consumes_propellant_and_time() {
+        let r1 = R_EARTH_ECI + 400_000.0;
+        let r2 = 42_164_000.0;
+        let v0 = circular_velocity(r1);
+        let vf = circular_velocity(r2);
+
+        let result = edelbaum_transfer(v0, vf, 7.0_f64.to_radians(), 0.5, 3000.0, 1500.0);
+        assert!(result.delta_v > 0.0);
+        assert!(result.transfer_time > 0.0);
+        assert_eq!(result.burn_time, result.transfer_time);
+        assert!(result.propellant_used > 0.0 && result.propellant_used < 1500.0);
+    }
+
+    #[test]
+    fn edelbaum_transfer_for_stage_matches_manual_inputs() {
+        use crate::vehicle::Engine;
+
+        let stage = Stage {
+            name: "Xenon kick stage".into(),
+            dry_mass: 400.0,
+            propellant_mass: 100.0,
+            engines: vec![Engine::new(0.5, 3000.0, 0.0)],
+            cd: 0.0,
+            area: 0.0,
+            inertia: Vector3::new(1.0, 1.0, 1.0),
+            cp_offset: 0.0,
+            tvc_max: 0.0,
+        };
+
+        let r1 = R_EARTH_ECI + 400_000.0;
+        let r2 = 42_164_000.0;
+        let v0 = circular_velocity(r1);
+        let vf = circular_velocity(r2);
+
+        let via_stage = edelbaum_transfer_for_stage(v0, vf, 7.0_f64.to_radians(), &stage);
+        let manual = edelbaum_transfer(v0, vf, 7.0_f64.to_radians(), stage.thrust(), stage.isp(), stage.total_mass());
+
+        assert_eq!(via_stage.delta_v, manual.delta_v);
+        assert_eq!(via_stage.transfer_time, manual.transfer_time);
+        assert_eq!(via_stage.propellant_used, manual.propellant_used);
+    }
+
+    fn circular_orbit_state(altitude: f64) -> OrbitalState {
+        let r = R_EARTH_ECI + altitude;
+        let v = circular_velocity(r);
+        OrbitalState {
+            time: 0.0,
+            pos: Vector3::new(r, 0.0, 0.0),
+            vel: Vector3::new(0.0, v, 0.0),
+        }
+    }
+
+    #[test]
+    fn finite_burn_prograde_raises_apoapsis() {
+        let initial = circular_orbit_state(400_000.0);
+        let burn = FiniteBurn {
+            thrust: 5000.0,
+            isp: 300.0,
+            steering: ThrustSteering::AlongVelocity,
+            dv_budget: 50.0,
+            max_duration: 600.0,
+        };
+
+        let result = simulate_finite_burn(&initial, 2000.0, &burn, 1.0, false);
+        let max_radius = result.trajectory.iter().map(|s| s.pos.norm()).fold(f64::MIN, f64::max);
+        assert!(max_radius > initial.pos.norm(), "prograde burn should raise apoapsis");
+        assert!(result.propellant_used > 0.0);
+    }
+
+    #[test]
+    fn finite_burn_respects_dv_budget() {
+        let initial = circular_orbit_state(400_000.0);
+        let burn = FiniteBurn {
+            thrust: 20_000.0,
+            isp: 300.0,
+            steering: ThrustSteering::AlongVelocity,
+            dv_budget: 10.0,
+            max_duration: 10_000.0,
+        };
+
+        let result = simulate_finite_burn(&initial, 5000.0, &burn, 1.0, false);
+        assert!(result.delta_v >= 10.0 && result.delta_v < 12.0,
+            "burn should stop close to its dv_budget, got {:.2}", result.delta_v);
+        assert!(result.burn_time < 10_000.0);
+    }
+
+    #[test]
+    fn thrust_steering_directions_are_unit_vectors() {
+        let state = circular_orbit_state(400_000.0);
+        let laws = [
+            ThrustSteering::AlongVelocity,
+            ThrustSteering::AntiVelocity,
+            ThrustSteering::InertialFixed(Vector3::new(1.0, 1.0, 0.0)),
+            ThrustSteering::TangentialPlusRadial { pitch: 0.3 },
+        ];
+        for law in laws {
+            let dir = law.direction(&state);
+            assert!((dir.norm() - 1.0).abs() < 1e-9);
+        }
+    }
 }
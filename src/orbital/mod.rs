@@ -2,6 +2,14 @@ pub mod elements;
 pub mod maneuvers;
 pub mod propagator;
 
-pub use elements::KeplerianElements;
-pub use maneuvers::{hohmann, HohmannTransfer};
-pub use propagator::{propagate_orbit, OrbitalState};
+pub use elements::{KeplerianElements, OrbitalElements};
+pub use maneuvers::{
+    bielliptic, combined_plane_change, edelbaum_delta_v, edelbaum_transfer,
+    edelbaum_transfer_for_stage, hohmann, inclination_change, simulate_finite_burn,
+    BiellipticTransfer, CombinedPlaneChangeTransfer, EdelbaumTransfer, FiniteBurn,
+    FiniteBurnResult, HohmannTransfer, ThrustSteering,
+};
+pub use propagator::{
+    propagate_orbit, propagate_orbit_adaptive, propagate_orbit_adaptive_with_third_body,
+    propagate_orbit_with_third_body, DragConfig, OrbitalState, ThirdBodyConfig,
+};
@@ -1,6 +1,45 @@
 use nalgebra::Vector3;
 
-use crate::physics::gravity::{gravity_j2_eci, gravity_pointmass_eci};
+use crate::dynamics::state::AdaptiveConfig;
+use crate::physics::atmosphere::drag_accel_eci;
+use crate::physics::gravity::{
+    gravity_j2_eci, gravity_pointmass_eci, moon_position, sun_position, third_body_acceleration,
+    MU_MOON, MU_SUN,
+};
+
+/// Ballistic properties needed to add atmospheric drag to a propagation.
+#[derive(Debug, Clone, Copy)]
+pub struct DragConfig {
+    pub cd: f64,
+    pub area: f64,
+    pub mass: f64,
+}
+
+/// Which third-body perturbations to include, so callers can isolate each
+/// effect (e.g. Moon-only for a cislunar trajectory, Sun-only for GEO drift).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThirdBodyConfig {
+    pub sun: bool,
+    pub moon: bool,
+}
+
+/// Sun/Moon perturbing acceleration at `pos`, from ephemerides evaluated at
+/// `time` (seconds past J2000). The ephemerides are evaluated on every call;
+/// the call sites in
+/// [`propagate_orbit_with_third_body`]/[`propagate_orbit_adaptive_with_third_body`]
+/// pass the step's start `time` to every sub-stage, holding the bodies fixed
+/// for the whole step the same way
+/// [`crate::dynamics::turbulence::DrydenField`] freezes its gust per step.
+fn third_body_accel(time: f64, pos: &Vector3<f64>, config: &ThirdBodyConfig) -> Vector3<f64> {
+    let mut a = Vector3::zeros();
+    if config.sun {
+        a += third_body_acceleration(pos, &sun_position(time), MU_SUN);
+    }
+    if config.moon {
+        a += third_body_acceleration(pos, &moon_position(time), MU_MOON);
+    }
+    a
+}
 
 /// Simplified 3DOF orbital state (no attitude).
 #[derive(Debug, Clone)]
@@ -20,14 +59,16 @@ impl OrbitalState {
     }
 }
 
-/// RK4 step for orbital propagation.
-fn rk4_orbital_step(
+/// RK4 step for orbital propagation. `pub(crate)` so `orbital::maneuvers`
+/// can reuse it for finite-burn integration instead of re-deriving the same
+/// stepper.
+pub(crate) fn rk4_orbital_step(
     state: &OrbitalState,
     dt: f64,
-    accel_fn: &dyn Fn(&Vector3<f64>) -> Vector3<f64>,
+    accel_fn: &dyn Fn(&Vector3<f64>, &Vector3<f64>) -> Vector3<f64>,
 ) -> OrbitalState {
     let deriv = |pos: &Vector3<f64>, vel: &Vector3<f64>| -> (Vector3<f64>, Vector3<f64>) {
-        (vel.clone(), accel_fn(pos))
+        (*vel, accel_fn(pos, vel))
     };
 
     let (k1_dr, k1_dv) = deriv(&state.pos, &state.vel);
@@ -51,7 +92,7 @@ fn rk4_orbital_step(
     }
 }
 
-/// Propagate an orbit with optional J2 perturbation.
+/// Propagate an orbit with optional J2 perturbation and atmospheric drag.
 ///
 /// Returns trajectory sampled at `dt` intervals for `duration` seconds.
 pub fn propagate_orbit(
@@ -59,8 +100,23 @@ pub fn propagate_orbit(
     dt: f64,
     duration: f64,
     use_j2: bool,
+    drag: Option<DragConfig>,
 ) -> Vec<OrbitalState> {
-    let accel_fn: Box<dyn Fn(&Vector3<f64>) -> Vector3<f64>> = if use_j2 {
+    propagate_orbit_with_third_body(initial, dt, duration, use_j2, drag, None)
+}
+
+/// Same as [`propagate_orbit`], additionally feeling Sun/Moon gravity when
+/// `third_body` is set. `initial.time` is treated as seconds past J2000 so
+/// [`sun_position`]/[`moon_position`] can be evaluated absolutely.
+pub fn propagate_orbit_with_third_body(
+    initial: &OrbitalState,
+    dt: f64,
+    duration: f64,
+    use_j2: bool,
+    drag: Option<DragConfig>,
+    third_body: Option<ThirdBodyConfig>,
+) -> Vec<OrbitalState> {
+    let gravity_fn: Box<dyn Fn(&Vector3<f64>) -> Vector3<f64>> = if use_j2 {
         Box::new(|pos: &Vector3<f64>| gravity_j2_eci(pos))
     } else {
         Box::new(|pos: &Vector3<f64>| gravity_pointmass_eci(pos))
@@ -72,7 +128,203 @@ pub fn propagate_orbit(
     trajectory.push(state.clone());
 
     for _ in 0..n_steps {
-        state = rk4_orbital_step(&state, dt, &*accel_fn);
+        // Sun/Moon barely move over one integration step, so (like a frozen
+        // wind field, see `dynamics::turbulence::DrydenField`) all 4 RK4
+        // sub-stages evaluate their ephemerides at the step's start time
+        // rather than at each sub-stage's own time.
+        let time = state.time;
+        let accel_fn = |pos: &Vector3<f64>, vel: &Vector3<f64>| -> Vector3<f64> {
+            let g = gravity_fn(pos);
+            let d = match drag {
+                Some(d) => drag_accel_eci(pos, vel, d.cd, d.area, d.mass),
+                None => Vector3::zeros(),
+            };
+            let tb = match third_body {
+                Some(cfg) => third_body_accel(time, pos, &cfg),
+                None => Vector3::zeros(),
+            };
+            g + d + tb
+        };
+        state = rk4_orbital_step(&state, dt, &accel_fn);
+        trajectory.push(state.clone());
+    }
+
+    trajectory
+}
+
+// ---------------------------------------------------------------------------
+// Adaptive-step propagation: Dormand-Prince 5(4) with error control
+// ---------------------------------------------------------------------------
+//
+// Same tableau as `sim::integrator::rkf45_step`, specialized to the
+// position/velocity-only orbital state (no quaternion/mass terms).
+
+struct OrbitalDeriv {
+    dpos: Vector3<f64>,
+    dvel: Vector3<f64>,
+}
+
+fn orbital_combo(terms: &[(f64, &OrbitalDeriv)]) -> OrbitalDeriv {
+    let mut out = OrbitalDeriv { dpos: Vector3::zeros(), dvel: Vector3::zeros() };
+    for (w, k) in terms {
+        out.dpos += k.dpos * *w;
+        out.dvel += k.dvel * *w;
+    }
+    out
+}
+
+fn orbital_apply(state: &OrbitalState, d: &OrbitalDeriv, dt: f64) -> OrbitalState {
+    OrbitalState {
+        time: state.time + dt,
+        pos: state.pos + d.dpos * dt,
+        vel: state.vel + d.dvel * dt,
+    }
+}
+
+fn orbital_error_norm(y5: &OrbitalState, y4: &OrbitalState, adaptive: &AdaptiveConfig) -> f64 {
+    let mut sum_sq = 0.0;
+    for i in 0..3 {
+        let sc_pos = adaptive.atol + adaptive.rtol * y5.pos[i].abs().max(y4.pos[i].abs());
+        let r_pos = (y5.pos[i] - y4.pos[i]) / sc_pos;
+        sum_sq += r_pos * r_pos;
+        let sc_vel = adaptive.atol + adaptive.rtol * y5.vel[i].abs().max(y4.vel[i].abs());
+        let r_vel = (y5.vel[i] - y4.vel[i]) / sc_vel;
+        sum_sq += r_vel * r_vel;
+    }
+    (sum_sq / 6.0).sqrt()
+}
+
+/// One adaptive Dormand-Prince 5(4) step, retrying with a shrunk `dt` until
+/// the local error estimate is within tolerance (mirrors
+/// [`crate::sim::integrator::rkf45_step`]).
+fn rkf45_orbital_step(
+    state: &OrbitalState,
+    dt_guess: f64,
+    adaptive: &AdaptiveConfig,
+    accel_fn: &dyn Fn(&Vector3<f64>, &Vector3<f64>) -> Vector3<f64>,
+) -> (OrbitalState, f64, f64) {
+    let deriv = |pos: &Vector3<f64>, vel: &Vector3<f64>| OrbitalDeriv { dpos: *vel, dvel: accel_fn(pos, vel) };
+    let mut dt = dt_guess.clamp(adaptive.dt_min, adaptive.dt_max);
+
+    loop {
+        let k1 = deriv(&state.pos, &state.vel);
+        let y2 = orbital_apply(state, &k1, dt * (1.0 / 5.0));
+        let k2 = deriv(&y2.pos, &y2.vel);
+
+        let s3 = orbital_combo(&[(3.0 / 40.0, &k1), (9.0 / 40.0, &k2)]);
+        let y3 = orbital_apply(state, &s3, dt);
+        let k3 = deriv(&y3.pos, &y3.vel);
+
+        let s4 = orbital_combo(&[(44.0 / 45.0, &k1), (-56.0 / 15.0, &k2), (32.0 / 9.0, &k3)]);
+        let y4s = orbital_apply(state, &s4, dt);
+        let k4 = deriv(&y4s.pos, &y4s.vel);
+
+        let s5 = orbital_combo(&[
+            (19372.0 / 6561.0, &k1),
+            (-25360.0 / 2187.0, &k2),
+            (64448.0 / 6561.0, &k3),
+            (-212.0 / 729.0, &k4),
+        ]);
+        let y5s = orbital_apply(state, &s5, dt);
+        let k5 = deriv(&y5s.pos, &y5s.vel);
+
+        let s6 = orbital_combo(&[
+            (9017.0 / 3168.0, &k1),
+            (-355.0 / 33.0, &k2),
+            (46732.0 / 5247.0, &k3),
+            (49.0 / 176.0, &k4),
+            (-5103.0 / 18656.0, &k5),
+        ]);
+        let y6s = orbital_apply(state, &s6, dt);
+        let k6 = deriv(&y6s.pos, &y6s.vel);
+
+        let s7 = orbital_combo(&[
+            (35.0 / 384.0, &k1),
+            (500.0 / 1113.0, &k3),
+            (125.0 / 192.0, &k4),
+            (-2187.0 / 6784.0, &k5),
+            (11.0 / 84.0, &k6),
+        ]);
+        let y5 = orbital_apply(state, &s7, dt);
+        let k7 = deriv(&y5.pos, &y5.vel);
+
+        let s4th = orbital_combo(&[
+            (5179.0 / 57600.0, &k1),
+            (7571.0 / 16695.0, &k3),
+            (393.0 / 640.0, &k4),
+            (-92097.0 / 339200.0, &k5),
+            (187.0 / 2100.0, &k6),
+            (1.0 / 40.0, &k7),
+        ]);
+        let y4 = orbital_apply(state, &s4th, dt);
+
+        let err_norm = orbital_error_norm(&y5, &y4, adaptive).max(1e-300);
+        let growth = (adaptive.safety * err_norm.powf(-1.0 / 5.0)).clamp(0.2, 5.0);
+        let dt_next = (dt * growth).clamp(adaptive.dt_min, adaptive.dt_max);
+
+        if err_norm <= 1.0 || dt <= adaptive.dt_min + 1e-12 {
+            return (y5, dt, dt_next);
+        }
+        dt = dt_next;
+    }
+}
+
+/// Same as [`propagate_orbit`] but steps with the adaptive Dormand-Prince
+/// 5(4) integrator, taking large steps through a quiet coast and shrinking
+/// automatically near perigee/drag-heavy regions. `dt_guess` is only the
+/// initial step-size guess.
+pub fn propagate_orbit_adaptive(
+    initial: &OrbitalState,
+    dt_guess: f64,
+    duration: f64,
+    use_j2: bool,
+    drag: Option<DragConfig>,
+    adaptive: &AdaptiveConfig,
+) -> Vec<OrbitalState> {
+    propagate_orbit_adaptive_with_third_body(initial, dt_guess, duration, use_j2, drag, None, adaptive)
+}
+
+/// Same as [`propagate_orbit_adaptive`], additionally feeling Sun/Moon
+/// gravity when `third_body` is set (see [`propagate_orbit_with_third_body`]
+/// for the ephemeris-freezing rationale).
+pub fn propagate_orbit_adaptive_with_third_body(
+    initial: &OrbitalState,
+    dt_guess: f64,
+    duration: f64,
+    use_j2: bool,
+    drag: Option<DragConfig>,
+    third_body: Option<ThirdBodyConfig>,
+    adaptive: &AdaptiveConfig,
+) -> Vec<OrbitalState> {
+    let gravity_fn: Box<dyn Fn(&Vector3<f64>) -> Vector3<f64>> = if use_j2 {
+        Box::new(|pos: &Vector3<f64>| gravity_j2_eci(pos))
+    } else {
+        Box::new(|pos: &Vector3<f64>| gravity_pointmass_eci(pos))
+    };
+
+    let mut trajectory = Vec::new();
+    let mut state = initial.clone();
+    trajectory.push(state.clone());
+
+    let mut dt_next = dt_guess;
+    while state.time < initial.time + duration {
+        let time = state.time;
+        let accel_fn = |pos: &Vector3<f64>, vel: &Vector3<f64>| -> Vector3<f64> {
+            let g = gravity_fn(pos);
+            let d = match drag {
+                Some(d) => drag_accel_eci(pos, vel, d.cd, d.area, d.mass),
+                None => Vector3::zeros(),
+            };
+            let tb = match third_body {
+                Some(cfg) => third_body_accel(time, pos, &cfg),
+                None => Vector3::zeros(),
+            };
+            g + d + tb
+        };
+        let dt_try = dt_next.min(initial.time + duration - state.time);
+        let (next, _dt_used, next_guess) = rkf45_orbital_step(&state, dt_try, adaptive, &accel_fn);
+        state = next;
+        dt_next = next_guess;
         trajectory.push(state.clone());
     }
 
@@ -96,7 +348,7 @@ mod tests {
 
         // Propagate one orbit (~92 min)
         let period = 2.0 * std::f64::consts::PI * (r.powi(3) / MU_EARTH).sqrt();
-        let traj = propagate_orbit(&initial, 1.0, period, false);
+        let traj = propagate_orbit(&initial, 1.0, period, false, None);
         let last = traj.last().unwrap();
 
         // Should return close to starting position (RK4 with dt=1s has ~1e-4 relative error)
@@ -123,8 +375,8 @@ mod tests {
         };
 
         let period = 2.0 * std::f64::consts::PI * (r.powi(3) / MU_EARTH).sqrt();
-        let traj_no_j2 = propagate_orbit(&initial, 1.0, period, false);
-        let traj_j2 = propagate_orbit(&initial, 1.0, period, true);
+        let traj_no_j2 = propagate_orbit(&initial, 1.0, period, false, None);
+        let traj_j2 = propagate_orbit(&initial, 1.0, period, true, None);
 
         let pos_no_j2 = traj_no_j2.last().unwrap().pos;
         let pos_j2 = traj_j2.last().unwrap().pos;
@@ -137,4 +389,128 @@ mod tests {
             diff
         );
     }
+
+    #[test]
+    fn drag_decays_low_orbit_altitude() {
+        let r = R_EARTH_ECI + 150_000.0; // low enough for the exponential model to bite
+        let v = (MU_EARTH / r).sqrt();
+        let initial = OrbitalState {
+            time: 0.0,
+            pos: Vector3::new(r, 0.0, 0.0),
+            vel: Vector3::new(0.0, v, 0.0),
+        };
+
+        let period = 2.0 * std::f64::consts::PI * (r.powi(3) / MU_EARTH).sqrt();
+        let drag = DragConfig { cd: 2.2, area: 1.0, mass: 50.0 };
+        let traj = propagate_orbit(&initial, 1.0, period * 3.0, false, Some(drag));
+
+        let first_altitude = traj.first().unwrap().altitude();
+        let last_altitude = traj.last().unwrap().altitude();
+        assert!(last_altitude < first_altitude, "Drag should lower orbital altitude over time");
+    }
+
+    #[test]
+    fn third_body_perturbation_moves_geo_orbit_off_track() {
+        // GEO is where Sun/Moon perturbations dominate long-term evolution,
+        // so the effect should already be visible over a single day.
+        let r = 42_164_140.0; // GEO radius, m
+        let v = (MU_EARTH / r).sqrt();
+        let initial = OrbitalState {
+            time: 0.0,
+            pos: Vector3::new(r, 0.0, 0.0),
+            vel: Vector3::new(0.0, v, 0.0),
+        };
+
+        let duration = 86_400.0;
+        let traj_two_body = propagate_orbit(&initial, 30.0, duration, false, None);
+        let traj_perturbed = propagate_orbit_with_third_body(
+            &initial, 30.0, duration, false, None,
+            Some(ThirdBodyConfig { sun: true, moon: true }),
+        );
+
+        let diff = (traj_perturbed.last().unwrap().pos - traj_two_body.last().unwrap().pos).norm();
+        assert!(diff > 1.0, "Sun+Moon should perturb a GEO orbit measurably over a day, got {:.3} m", diff);
+    }
+
+    #[test]
+    fn third_body_flags_gate_independently() {
+        let r = 42_164_140.0;
+        let v = (MU_EARTH / r).sqrt();
+        let initial = OrbitalState {
+            time: 0.0,
+            pos: Vector3::new(r, 0.0, 0.0),
+            vel: Vector3::new(0.0, v, 0.0),
+        };
+
+        let duration = 86_400.0;
+        let none = propagate_orbit_with_third_body(&initial, 30.0, duration, false, None, None);
+        let sun_only = propagate_orbit_with_third_body(
+            &initial, 30.0, duration, false, None, Some(ThirdBodyConfig { sun: true, moon: false }),
+        );
+        let moon_only = propagate_orbit_with_third_body(
+            &initial, 30.0, duration, false, None, Some(ThirdBodyConfig { sun: false, moon: true }),
+        );
+
+        assert!((sun_only.last().unwrap().pos - none.last().unwrap().pos).norm() > 1.0);
+        assert!((moon_only.last().unwrap().pos - none.last().unwrap().pos).norm() > 1.0);
+    }
+
+    #[test]
+    fn adaptive_third_body_matches_fixed_step_closely() {
+        let r = 42_164_140.0;
+        let v = (MU_EARTH / r).sqrt();
+        let initial = OrbitalState {
+            time: 0.0,
+            pos: Vector3::new(r, 0.0, 0.0),
+            vel: Vector3::new(0.0, v, 0.0),
+        };
+        let cfg = ThirdBodyConfig { sun: true, moon: true };
+
+        let duration = 3_600.0 * 6.0;
+        let fixed = propagate_orbit_with_third_body(&initial, 10.0, duration, true, None, Some(cfg));
+        let adaptive = propagate_orbit_adaptive_with_third_body(
+            &initial, 10.0, duration, true, None, Some(cfg), &AdaptiveConfig::default(),
+        );
+
+        let diff = (fixed.last().unwrap().pos - adaptive.last().unwrap().pos).norm();
+        assert!(diff < 1_000.0, "adaptive and fixed-step third-body propagation should agree closely, got {:.1} m", diff);
+    }
+
+    #[test]
+    fn adaptive_circular_orbit_stays_circular() {
+        let r = R_EARTH_ECI + 400_000.0;
+        let v = (MU_EARTH / r).sqrt();
+        let initial = OrbitalState {
+            time: 0.0,
+            pos: Vector3::new(r, 0.0, 0.0),
+            vel: Vector3::new(0.0, v, 0.0),
+        };
+
+        let period = 2.0 * std::f64::consts::PI * (r.powi(3) / MU_EARTH).sqrt();
+        let traj = propagate_orbit_adaptive(&initial, 1.0, period, false, None, &AdaptiveConfig::default());
+        let last = traj.last().unwrap();
+
+        let pos_error = (last.pos - initial.pos).norm();
+        let orbit_circumference = 2.0 * std::f64::consts::PI * r;
+        assert!(
+            pos_error / orbit_circumference < 2e-4,
+            "adaptive propagation should close the orbit about as well as fixed-step RK4"
+        );
+    }
+
+    #[test]
+    fn adaptive_uses_far_fewer_samples_on_quiet_orbit() {
+        let r = R_EARTH_ECI + 400_000.0;
+        let v = (MU_EARTH / r).sqrt();
+        let initial = OrbitalState {
+            time: 0.0,
+            pos: Vector3::new(r, 0.0, 0.0),
+            vel: Vector3::new(0.0, v, 0.0),
+        };
+
+        let period = 2.0 * std::f64::consts::PI * (r.powi(3) / MU_EARTH).sqrt();
+        let fixed = propagate_orbit(&initial, 1.0, period, false, None);
+        let adaptive = propagate_orbit_adaptive(&initial, 1.0, period, false, None, &AdaptiveConfig::default());
+        assert!(adaptive.len() < fixed.len(), "adaptive stepping should take far fewer samples on an unperturbed orbit");
+    }
 }
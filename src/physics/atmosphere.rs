@@ -0,0 +1,259 @@
+use nalgebra::Vector3;
+
+use crate::dynamics::state::G0;
+use crate::physics::gravity::R_EARTH_ECI;
+
+// ---------------------------------------------------------------------------
+// ISA 1976 Standard Atmosphere (sea level to 86 km)
+// ---------------------------------------------------------------------------
+
+const R_AIR: f64 = 287.052_87; // specific gas constant for dry air, J/(kg·K)
+const GAMMA: f64 = 1.4;        // ratio of specific heats
+
+const T0: f64 = 288.15;        // sea-level temperature, K
+const P0: f64 = 101_325.0;     // sea-level pressure, Pa
+
+/// Atmospheric properties at a given geometric altitude.
+#[derive(Debug, Clone, Copy)]
+pub struct Atmo {
+    pub density: f64,      // kg/m^3
+    pub pressure: f64,     // Pa
+    pub temperature: f64,  // K
+    pub sound_speed: f64,  // m/s
+}
+
+/// ISA 1976 standard atmosphere model.
+///
+/// Piecewise temperature profile with 7 layers from 0-86 km.
+/// Clamps negative altitudes to sea level; returns near-vacuum above 86 km.
+pub fn isa(altitude_m: f64) -> Atmo {
+    let h = altitude_m.max(0.0);
+
+    let (temperature, pressure) = if h < 11_000.0 {
+        // Troposphere: lapse -6.5 K/km
+        gradient_layer(h, 0.0, T0, -0.0065, P0)
+    } else if h < 20_000.0 {
+        // Tropopause: isothermal 216.65 K
+        isothermal_layer(h, 11_000.0, 216.65, 22_632.1)
+    } else if h < 32_000.0 {
+        // Stratosphere I: lapse +1.0 K/km
+        gradient_layer(h, 20_000.0, 216.65, 0.001, 5_474.89)
+    } else if h < 47_000.0 {
+        // Stratosphere II: lapse +2.8 K/km
+        gradient_layer(h, 32_000.0, 228.65, 0.0028, 868.019)
+    } else if h < 51_000.0 {
+        // Mesosphere I: isothermal 270.65 K
+        isothermal_layer(h, 47_000.0, 270.65, 110.906)
+    } else if h < 71_000.0 {
+        // Mesosphere II: lapse -2.8 K/km
+        gradient_layer(h, 51_000.0, 270.65, -0.0028, 66.9389)
+    } else if h < 86_000.0 {
+        // Mesosphere III: lapse -2.0 K/km
+        gradient_layer(h, 71_000.0, 214.65, -0.002, 3.956_42)
+    } else {
+        // Above 86 km: exponential decay approximation
+        let t = 186.87;
+        let p = 0.3734 * (-0.000_15 * (h - 86_000.0)).exp();
+        (t, p.max(0.0))
+    };
+
+    let density = if temperature > 0.0 {
+        pressure / (R_AIR * temperature)
+    } else {
+        0.0
+    };
+
+    Atmo {
+        density,
+        pressure,
+        temperature,
+        sound_speed: (GAMMA * R_AIR * temperature).sqrt(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Layer helpers
+// ---------------------------------------------------------------------------
+
+/// Gradient layer: T = T_base + lapse * (h - h_base)
+fn gradient_layer(h: f64, h_base: f64, t_base: f64, lapse: f64, p_base: f64) -> (f64, f64) {
+    let t = t_base + lapse * (h - h_base);
+    let p = p_base * (t / t_base).powf(-G0 / (lapse * R_AIR));
+    (t, p)
+}
+
+/// Isothermal layer: T = const, pressure decays exponentially
+fn isothermal_layer(h: f64, h_base: f64, t: f64, p_base: f64) -> (f64, f64) {
+    let p = p_base * ((-G0 / (R_AIR * t)) * (h - h_base)).exp();
+    (t, p)
+}
+
+// ---------------------------------------------------------------------------
+// Piecewise-exponential density (fast, for orbital decay & drag)
+// ---------------------------------------------------------------------------
+//
+// `isa()` above is accurate but only defined to 86 km and isn't meant to be
+// cheap. Orbital decay needs density evaluated over hundreds of periods from
+// sea level out to ~1000 km, so we fit ρ(h) = ρ0_i · exp(−(h − h0_i)/H_i)
+// over a few altitude bands instead, each with a tabulated base density.
+// Only the sea-level band's ρ0 equals the ISA value; the bands are not
+// matched to `isa()` and do not meet exactly at their seams.
+
+struct ExponentialBand {
+    h0: f64,   // base altitude, m
+    rho0: f64, // density at h0, kg/m^3
+    scale_height: f64, // m
+}
+
+const EXPONENTIAL_BANDS: [ExponentialBand; 3] = [
+    ExponentialBand { h0: 0.0, rho0: 1.225, scale_height: 7_249.0 },         // 0-25 km
+    ExponentialBand { h0: 25_000.0, rho0: 3.899e-2, scale_height: 6_349.0 }, // 25-100 km
+    ExponentialBand { h0: 100_000.0, rho0: 5.297e-7, scale_height: 50_000.0 }, // 100-1000 km
+];
+
+const TOP_BAND_CEILING: f64 = 1_000_000.0; // m; above this, density ~0
+
+/// Piecewise-exponential atmospheric density (kg/m^3).
+///
+/// Clamps below sea level and returns ~0 above the top tabulated band.
+pub fn density_exponential(altitude_m: f64) -> f64 {
+    let h = altitude_m.max(0.0);
+    if h >= TOP_BAND_CEILING {
+        return 0.0;
+    }
+
+    let band = EXPONENTIAL_BANDS
+        .iter()
+        .rev()
+        .find(|b| h >= b.h0)
+        .unwrap_or(&EXPONENTIAL_BANDS[0]);
+
+    band.rho0 * (-(h - band.h0) / band.scale_height).exp()
+}
+
+// ---------------------------------------------------------------------------
+// Drag acceleration
+// ---------------------------------------------------------------------------
+
+/// Earth's sidereal rotation rate, rad/s, about +z.
+pub const OMEGA_EARTH: f64 = 7.292_115e-5;
+
+/// Drag deceleration in ECI coordinates, accounting for the co-rotating
+/// atmosphere (`v_rel = v − ω_earth × r`). Uses the piecewise-exponential
+/// density model since ECI altitudes span well past 86 km.
+pub fn drag_accel_eci(pos: &Vector3<f64>, vel: &Vector3<f64>, cd: f64, area: f64, mass: f64) -> Vector3<f64> {
+    let omega = Vector3::new(0.0, 0.0, OMEGA_EARTH);
+    let v_rel = vel - omega.cross(pos);
+    let altitude = pos.norm() - R_EARTH_ECI;
+    drag_accel(&v_rel, altitude, cd, area, mass)
+}
+
+/// Drag deceleration in a local (non-rotating) frame, e.g. 6DOF ascent/descent
+/// where the atmosphere's co-rotation is negligible over the flight.
+pub fn drag_accel_local(vel: &Vector3<f64>, altitude_m: f64, cd: f64, area: f64, mass: f64) -> Vector3<f64> {
+    drag_accel(vel, altitude_m, cd, area, mass)
+}
+
+/// Same as [`drag_accel_eci`], under the `(pos, vel, cd, area, mass)` name
+/// callers reaching for a standalone drag function most often expect.
+pub fn drag_acceleration(pos: &Vector3<f64>, vel: &Vector3<f64>, cd: f64, area: f64, mass: f64) -> Vector3<f64> {
+    drag_accel_eci(pos, vel, cd, area, mass)
+}
+
+fn drag_accel(v_rel: &Vector3<f64>, altitude_m: f64, cd: f64, area: f64, mass: f64) -> Vector3<f64> {
+    let speed = v_rel.norm();
+    if speed < 1e-6 || mass <= 0.0 {
+        return Vector3::zeros();
+    }
+    let rho = density_exponential(altitude_m);
+    -0.5 * rho * (cd * area / mass) * speed * v_rel
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sea_level_standard_values() {
+        let a = isa(0.0);
+        assert!((a.temperature - 288.15).abs() < 0.01);
+        assert!((a.pressure - 101_325.0).abs() < 1.0);
+        assert!((a.density - 1.225).abs() < 0.001);
+        assert!((a.sound_speed - 340.29).abs() < 0.1);
+    }
+
+    #[test]
+    fn tropopause_11km() {
+        let a = isa(11_000.0);
+        assert!((a.temperature - 216.65).abs() < 0.5);
+        assert!((a.pressure - 22_632.0).abs() < 100.0);
+    }
+
+    #[test]
+    fn density_monotonically_decreases() {
+        let rho_0 = isa(0.0).density;
+        let rho_10k = isa(10_000.0).density;
+        let rho_50k = isa(50_000.0).density;
+        assert!(rho_0 > rho_10k);
+        assert!(rho_10k > rho_50k);
+        assert!(rho_50k > 0.0);
+    }
+
+    #[test]
+    fn negative_altitude_clamps_to_sea_level() {
+        let a = isa(-500.0);
+        assert!((a.temperature - 288.15).abs() < 0.01);
+    }
+
+    #[test]
+    fn near_vacuum_above_86km() {
+        let a = isa(100_000.0);
+        assert!(a.density < 1e-5);
+        assert!(a.pressure < 1.0);
+    }
+
+    #[test]
+    fn exponential_density_matches_isa_at_sea_level() {
+        assert!((density_exponential(0.0) - 1.225).abs() < 1e-6);
+    }
+
+    #[test]
+    fn exponential_density_decreases_with_altitude() {
+        let rho_leo = density_exponential(400_000.0);
+        let rho_mid = density_exponential(100_000.0);
+        assert!(rho_mid > rho_leo);
+        assert!(rho_leo > 0.0);
+    }
+
+    #[test]
+    fn exponential_density_vanishes_above_top_band() {
+        assert_eq!(density_exponential(2_000_000.0), 0.0);
+    }
+
+    #[test]
+    fn drag_accel_opposes_relative_velocity() {
+        let pos = Vector3::new(R_EARTH_ECI + 200_000.0, 0.0, 0.0);
+        let vel = Vector3::new(0.0, 7_800.0, 0.0);
+        let a = drag_accel_eci(&pos, &vel, 2.2, 1.0, 500.0);
+        assert!(a.y < 0.0, "Drag should decelerate along the velocity direction");
+    }
+
+    #[test]
+    fn no_drag_at_rest() {
+        let a = drag_accel_local(&Vector3::zeros(), 1_000.0, 0.3, 0.01, 20.0);
+        assert!(a.norm() < 1e-10);
+    }
+
+    #[test]
+    fn drag_acceleration_matches_drag_accel_eci() {
+        let pos = Vector3::new(R_EARTH_ECI + 300_000.0, 0.0, 0.0);
+        let vel = Vector3::new(0.0, 7_700.0, 0.0);
+        let a = drag_acceleration(&pos, &vel, 2.2, 1.0, 500.0);
+        let b = drag_accel_eci(&pos, &vel, 2.2, 1.0, 500.0);
+        assert_eq!(a, b);
+    }
+}
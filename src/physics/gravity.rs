@@ -22,6 +22,7 @@ pub fn gravity_force(altitude: f64, mass: f64) -> Vector3<f64> {
 pub const MU_EARTH: f64 = 3.986_004_418e14;  // m^3/s^2
 pub const R_EARTH_ECI: f64 = 6_378_137.0;    // equatorial radius, m
 pub const J2_EARTH: f64 = 1.082_63e-3;
+pub const EARTH_ROTATION_RATE: f64 = 7.292_115e-5; // rad/s, sidereal
 
 /// J2 gravitational acceleration in ECI frame.
 /// `pos` is the position vector in ECI coordinates (m).
@@ -51,6 +52,93 @@ pub fn gravity_pointmass_eci(pos: &Vector3<f64>) -> Vector3<f64> {
     -MU_EARTH / (r * r * r) * pos
 }
 
+// ---------------------------------------------------------------------------
+// Third-body perturbations (Sun/Moon), low-precision analytic ephemerides
+// ---------------------------------------------------------------------------
+//
+// Full JPL-grade ephemerides are overkill for perturbation magnitudes — these
+// are the truncated trigonometric series from Montenbruck & Gill, "Satellite
+// Orbits" §3.3 (good to a few arcminutes), which is plenty to feel the Sun
+// and Moon's tug on a propagated orbit.
+
+pub const MU_SUN: f64 = 1.327_124_400_18e20; // m^3/s^2
+pub const MU_MOON: f64 = 4.902_800_066e12;   // m^3/s^2
+const AU_M: f64 = 1.495_978_707e11;          // m
+const OBLIQUITY_J2000_DEG: f64 = 23.439_29;  // mean obliquity of the ecliptic at J2000
+
+/// Rotate ecliptic-frame Cartesian coordinates into the equatorial (ECI)
+/// frame by the mean obliquity of the ecliptic.
+fn ecliptic_to_equatorial(x: f64, y: f64, z: f64) -> Vector3<f64> {
+    let eps = OBLIQUITY_J2000_DEG.to_radians();
+    Vector3::new(x, y * eps.cos() - z * eps.sin(), y * eps.sin() + z * eps.cos())
+}
+
+/// Low-precision Sun position in the ECI (equatorial) frame, from a Keplerian
+/// solar orbit's mean longitude and anomaly (Montenbruck & Gill §3.3.2).
+/// `t_j2000_s` is seconds past the J2000.0 epoch.
+pub fn sun_position(t_j2000_s: f64) -> Vector3<f64> {
+    let d = t_j2000_s / 86_400.0;
+
+    let m = (357.5256 + 0.985_600_28 * d).to_radians();
+    let mean_longitude = (280.460 + 0.985_647_4 * d).to_radians();
+    let lambda = mean_longitude
+        + 1.915_f64.to_radians() * m.sin()
+        + 0.020_f64.to_radians() * (2.0 * m).sin();
+    let r = (1.000_14 - 0.016_71 * m.cos() - 0.000_14 * (2.0 * m).cos()) * AU_M;
+
+    ecliptic_to_equatorial(r * lambda.cos(), r * lambda.sin(), 0.0)
+}
+
+/// Low-precision Moon position in the ECI (equatorial) frame, from the
+/// short truncated series in the fundamental lunar arguments (Montenbruck &
+/// Gill §3.3.3). `t_j2000_s` is seconds past the J2000.0 epoch.
+pub fn moon_position(t_j2000_s: f64) -> Vector3<f64> {
+    let t = t_j2000_s / 86_400.0 / 36_525.0;
+
+    let l0 = (218.316_17 + 481_267.880_88 * t).to_radians(); // mean longitude
+    let l = (134.962_92 + 477_198.867_53 * t).to_radians();  // mean anomaly
+    let lp = (357.525_43 + 35_999.049_44 * t).to_radians();  // Sun's mean anomaly
+    let f = (93.272_83 + 483_202.018_73 * t).to_radians();   // argument of latitude
+    let d = (297.850_27 + 445_267.111_35 * t).to_radians();  // mean elongation from Sun
+
+    let lambda = l0
+        + 6.288_75_f64.to_radians() * l.sin()
+        + 1.274_02_f64.to_radians() * (2.0 * d - l).sin()
+        + 0.657_91_f64.to_radians() * (2.0 * d).sin()
+        + 0.213_62_f64.to_radians() * (2.0 * l).sin()
+        - 0.185_63_f64.to_radians() * lp.sin()
+        - 0.111_24_f64.to_radians() * (2.0 * f).sin();
+
+    let beta = 5.128_19_f64.to_radians() * f.sin()
+        + 0.280_58_f64.to_radians() * (l + f).sin()
+        - 0.277_26_f64.to_radians() * (l - f).sin()
+        + 0.006_66_f64.to_radians() * (2.0 * d - f).sin();
+
+    let distance_km = 385_000.56
+        - 20_905.36 * l.cos()
+        - 3_699.11 * (2.0 * d - l).cos()
+        - 2_955.97 * (2.0 * d).cos()
+        - 569.93 * (2.0 * l).cos();
+    let r = distance_km * 1000.0;
+
+    ecliptic_to_equatorial(r * beta.cos() * lambda.cos(), r * beta.cos() * lambda.sin(), r * beta.sin())
+}
+
+/// Third-body gravitational perturbation on a satellite from a body at
+/// `body_pos` (e.g. [`sun_position`]/[`moon_position`]), keeping the
+/// indirect (central-body reflex) term so it can be added straight onto
+/// Earth-centered two-body/J2 acceleration: `d = body_pos - sat_pos`,
+/// `mu_body * (d/|d|^3 - body_pos/|body_pos|^3)`.
+pub fn third_body_acceleration(sat_pos: &Vector3<f64>, body_pos: &Vector3<f64>, mu_body: f64) -> Vector3<f64> {
+    let d = body_pos - sat_pos;
+    let d_norm3 = d.norm().powi(3);
+    let body_norm3 = body_pos.norm().powi(3);
+    if d_norm3 < 1.0 || body_norm3 < 1.0 {
+        return Vector3::zeros();
+    }
+    mu_body * (d / d_norm3 - body_pos / body_norm3)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,4 +165,48 @@ mod tests {
         let diff = (a_j2 - a_pm).norm() / a_pm.norm();
         assert!(diff < 0.01, "J2 correction should be <1% at LEO, got {:.4}%", diff * 100.0);
     }
+
+    #[test]
+    fn sun_position_is_about_one_au() {
+        let p = sun_position(0.0);
+        let au_ratio = p.norm() / AU_M;
+        assert!((au_ratio - 1.0).abs() < 0.02, "Sun distance should be ~1 AU, got {:.4} AU", au_ratio);
+    }
+
+    #[test]
+    fn moon_position_is_within_perigee_apogee_range() {
+        let p = moon_position(0.0);
+        let dist_km = p.norm() / 1000.0;
+        assert!(
+            (356_000.0..407_000.0).contains(&dist_km),
+            "Moon distance out of perigee/apogee range: {:.0} km",
+            dist_km
+        );
+    }
+
+    #[test]
+    fn ephemerides_drift_with_time() {
+        let sun_now = sun_position(0.0);
+        let sun_later = sun_position(30.0 * 86_400.0);
+        assert!((sun_now - sun_later).norm() > 1e9, "Sun should move noticeably over 30 days");
+
+        let moon_now = moon_position(0.0);
+        let moon_later = moon_position(7.0 * 86_400.0);
+        assert!((moon_now - moon_later).norm() > 1e7, "Moon should move noticeably over 7 days");
+    }
+
+    #[test]
+    fn third_body_acceleration_vanishes_at_the_central_body() {
+        let body = Vector3::new(1.5e11, 0.0, 0.0);
+        let a = third_body_acceleration(&Vector3::zeros(), &body, MU_SUN);
+        assert!(a.norm() < 1e-9, "no perturbation at the point the ephemeris is centered on");
+    }
+
+    #[test]
+    fn third_body_acceleration_pulls_toward_the_body() {
+        let body = Vector3::new(4.0e8, 0.0, 0.0); // moon-like distance
+        let sat = Vector3::new(1.0e8, 0.0, 0.0);
+        let a = third_body_acceleration(&sat, &body, MU_MOON);
+        assert!(a.x > 0.0, "perturbation should pull toward the third body");
+    }
 }
@@ -0,0 +1,3 @@
+pub mod atmosphere;
+pub mod aerodynamics;
+pub mod gravity;
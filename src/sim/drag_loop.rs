@@ -0,0 +1,78 @@
+use nalgebra::UnitQuaternion;
+
+use crate::dynamics::state::{GncCommand, SimConfig, State};
+use crate::gnc::Controller;
+use crate::vehicle::Mission;
+use super::integrator::rk4_step;
+use super::runner::{check_staging, init_state};
+
+// ---------------------------------------------------------------------------
+// Shared drag-modifier + event-check stepping core
+// ---------------------------------------------------------------------------
+//
+// Used by `recovery::simulate_with_recovery` (concrete `Parachute` list) and
+// `phase::simulate_with_events` (trait-object `FlightEvent` list): both
+// derive the active stage's effective (cd, area) each step from some list of
+// deployed drag contributions, integrate, then check that same list for new
+// firings. The state-init/step/launched/ground-impact/push shell is common
+// (mirroring `runner::run_loop`); only `drag_for` (this step's effective
+// drag) and `on_event` (detect/record/apply a firing) differ per caller.
+
+/// Runs the shared stepping loop and returns the trajectory. `drag_for`
+/// computes the active stage's effective (cd, area) for the upcoming step
+/// from `state` (the pre-step state). `on_event` is called with (pre-step
+/// state, post-step state, the mission clone the step was flown against,
+/// the command flown) so callers can detect/record/apply firings against
+/// the exact inputs the step used. `apply_staging` mirrors whether the
+/// caller wants `runner::check_staging` applied each step (recovery's loop
+/// predates staging support and doesn't advance stages; phase's does).
+pub(crate) fn drag_event_loop(
+    mission: &Mission,
+    config: &SimConfig,
+    controller: &mut dyn Controller,
+    apply_staging: bool,
+    mut drag_for: impl FnMut(&State) -> (f64, f64),
+    mut on_event: impl FnMut(&State, &State, &Mission, &GncCommand),
+) -> Vec<State> {
+    let mut state = init_state(mission, UnitQuaternion::identity());
+
+    let capacity = (config.max_time / config.dt) as usize + 1;
+    let cap = capacity.min(200_000);
+    let mut trajectory = Vec::with_capacity(cap);
+    trajectory.push(state.clone());
+
+    let mut launched = false;
+
+    while state.time < config.max_time {
+        let cmd = controller.control(&state, mission, config.dt);
+
+        let (cd, area) = drag_for(&state);
+        let mut mission_step = mission.clone();
+        if let Some(stage) = mission_step.stages.get_mut(state.stage_idx) {
+            stage.cd = cd;
+            stage.area = area;
+        }
+
+        let mut next = rk4_step(&state, &mission_step, &cmd, config.dt);
+        if apply_staging {
+            check_staging(&mut next, mission);
+        }
+
+        on_event(&state, &next, &mission_step, &cmd);
+
+        state = next;
+
+        if state.pos.z > 1.0 {
+            launched = true;
+        }
+        if launched && state.pos.z <= 0.0 {
+            state.pos.z = 0.0;
+            trajectory.push(state);
+            break;
+        }
+
+        trajectory.push(state.clone());
+    }
+
+    trajectory
+}
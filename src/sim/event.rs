@@ -92,6 +92,7 @@ mod tests {
             omega: Vector3::zeros(),
             mass: 100.0,
             stage_idx: 0,
+            stage_ignition_time: 0.0,
         }
     }
 
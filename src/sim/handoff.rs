@@ -0,0 +1,136 @@
+use nalgebra::Vector3;
+
+use crate::dynamics::state::{SimConfig, State};
+use crate::gnc::Controller;
+use crate::orbital::{propagate_orbit, OrbitalElements, OrbitalState};
+use crate::physics::gravity::{EARTH_ROTATION_RATE, R_EARTH_ECI};
+use crate::vehicle::Mission;
+use super::runner::simulate_with;
+
+// ---------------------------------------------------------------------------
+// Ascent-to-orbit handoff
+// ---------------------------------------------------------------------------
+//
+// Bridges the 6DOF launch-frame ascent (`sim::simulate_with`) and the ECI
+// orbital propagator (`orbital::propagate_orbit`), which otherwise never
+// talk to each other: detect final-stage burnout, rotate the launch-frame
+// state into ECI, and continue the trajectory as an orbit.
+
+/// Convert a launch-frame (ENU, origin at the pad) state into the ECI frame,
+/// accounting for the launch site's latitude/longitude and Earth's rotation
+/// since liftoff (`state.time`). The launch-frame ECEF axes are assumed to
+/// coincide with ECI at `t = 0`.
+pub fn launch_frame_to_eci(state: &State, launch_lat_rad: f64, launch_lon_rad: f64) -> OrbitalState {
+    let (sin_lat, cos_lat) = launch_lat_rad.sin_cos();
+    let (sin_lon, cos_lon) = launch_lon_rad.sin_cos();
+
+    let east = Vector3::new(-sin_lon, cos_lon, 0.0);
+    let north = Vector3::new(-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat);
+    let up = Vector3::new(cos_lat * cos_lon, cos_lat * sin_lon, sin_lat);
+    let site_ecef = R_EARTH_ECI * up;
+
+    let pos_ecef = site_ecef + east * state.pos.x + north * state.pos.y + up * state.pos.z;
+    let vel_ecef = east * state.vel.x + north * state.vel.y + up * state.vel.z;
+
+    // Rotate ECEF -> ECI by the sidereal angle elapsed since liftoff.
+    let theta = EARTH_ROTATION_RATE * state.time;
+    let (s, c) = theta.sin_cos();
+    let pos_eci = Vector3::new(
+        c * pos_ecef.x - s * pos_ecef.y,
+        s * pos_ecef.x + c * pos_ecef.y,
+        pos_ecef.z,
+    );
+    let vel_eci_rot = Vector3::new(
+        c * vel_ecef.x - s * vel_ecef.y,
+        s * vel_ecef.x + c * vel_ecef.y,
+        vel_ecef.z,
+    );
+    let omega_earth = Vector3::new(0.0, 0.0, EARTH_ROTATION_RATE);
+    let vel_eci = vel_eci_rot + omega_earth.cross(&pos_eci);
+
+    OrbitalState { time: state.time, pos: pos_eci, vel: vel_eci }
+}
+
+/// Find the burnout state of the final stage: the first trajectory sample
+/// where the active stage is the last one and its propellant is exhausted
+/// (same `remaining_prop <= 0.01` threshold `check_staging` uses). Falls
+/// back to the final trajectory sample if burnout is never reached.
+fn find_burnout(trajectory: &[State], mission: &Mission) -> State {
+    let last_stage = mission.stages.len().saturating_sub(1);
+    trajectory
+        .iter()
+        .find(|s| {
+            s.stage_idx == last_stage
+                && mission.stages.get(last_stage).map_or(false, |stage| s.mass - stage.dry_mass <= 0.01)
+        })
+        .cloned()
+        .unwrap_or_else(|| trajectory.last().unwrap().clone())
+}
+
+/// Run the full 6DOF ascent, hand off at final-stage burnout into the ECI
+/// orbital propagator, and report the resulting orbit. Returns the ascent
+/// trajectory, the post-burnout ECI trajectory, and the achieved elements.
+#[allow(clippy::too_many_arguments)]
+pub fn ascent_to_orbit(
+    mission: &Mission,
+    config: &SimConfig,
+    controller: &mut dyn Controller,
+    launch_lat_deg: f64,
+    launch_lon_deg: f64,
+    orbit_dt: f64,
+    orbit_duration: f64,
+    use_j2: bool,
+) -> (Vec<State>, Vec<OrbitalState>, OrbitalElements) {
+    let (ascent_traj, _) = simulate_with(mission, config, controller);
+    let burnout = find_burnout(&ascent_traj, mission);
+
+    let eci_initial = launch_frame_to_eci(&burnout, launch_lat_deg.to_radians(), launch_lon_deg.to_radians());
+    let orbit_traj = propagate_orbit(&eci_initial, orbit_dt, orbit_duration, use_j2, None);
+
+    let final_eci = orbit_traj.last().unwrap();
+    let elements = OrbitalElements::from_state_vector(&final_eci.pos, &final_eci.vel);
+
+    (ascent_traj, orbit_traj, elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gnc::TvcController;
+    use crate::vehicle::presets;
+
+    #[test]
+    fn launch_frame_at_equator_t0_matches_ecef() {
+        // At t=0 and the equator/prime meridian, ENU (x=east,y=north,z=up)
+        // should map onto ECI (y, x flipped appropriately) with no rotation.
+        let state = State {
+            time: 0.0,
+            pos: Vector3::new(0.0, 0.0, 400_000.0),
+            vel: Vector3::new(0.0, 0.0, 0.0),
+            quat: nalgebra::UnitQuaternion::identity(),
+            omega: Vector3::zeros(),
+            mass: 100.0,
+            stage_idx: 0,
+            stage_ignition_time: 0.0,
+        };
+        let eci = launch_frame_to_eci(&state, 0.0, 0.0);
+        assert!((eci.pos.norm() - (R_EARTH_ECI + 400_000.0)).abs() < 1.0);
+    }
+
+    #[test]
+    fn ascent_to_orbit_runs_pathfinder() {
+        let mission = presets::pathfinder();
+        let config = SimConfig { dt: 0.01, max_time: 300.0 };
+        let mut controller = TvcController::new();
+        let (ascent, orbit, elements) =
+            ascent_to_orbit(&mission, &config, &mut controller, 28.5, -80.6, 1.0, 60.0, false);
+
+        assert!(!ascent.is_empty());
+        assert!(!orbit.is_empty());
+        // A small sounding rocket stage won't reach orbital velocity, so the
+        // handoff should report a sub-orbital (high-eccentricity or negative
+        // apoapsis) result rather than panic or produce NaNs.
+        assert!(elements.sma.is_finite());
+        assert!(elements.ecc.is_finite());
+    }
+}
@@ -1,7 +1,8 @@
-use nalgebra::UnitQuaternion;
+use nalgebra::{Quaternion, UnitQuaternion, Vector3};
 
 use crate::dynamics;
-use crate::dynamics::state::{GncCommand, State};
+use crate::dynamics::state::{AdaptiveConfig, Deriv, GncCommand, State, WindModel};
+use crate::dynamics::turbulence::WindField;
 use crate::vehicle::Mission;
 
 // ---------------------------------------------------------------------------
@@ -29,5 +30,189 @@ pub fn rk4_step(state: &State, mission: &Mission, cmd: &GncCommand, dt: f64) ->
             + (k1.dmass + 2.0 * k2.dmass + 2.0 * k3.dmass + k4.dmass) * (dt / 6.0))
             .max(0.0),
         stage_idx: state.stage_idx,
+        stage_ignition_time: state.stage_ignition_time,
+    }
+}
+
+/// Same as [`rk4_step`] but threads a [`WindModel`] through every stage via
+/// [`dynamics::derivatives_with_wind`], so drag and angle-of-attack see the
+/// air-relative velocity instead of the inertial one.
+pub fn rk4_step_with_wind(state: &State, mission: &Mission, cmd: &GncCommand, dt: f64, wind: &WindModel) -> State {
+    let k1 = dynamics::derivatives_with_wind(state, mission, cmd, wind);
+    let k2 = dynamics::derivatives_with_wind(&state.apply(&k1, dt * 0.5), mission, cmd, wind);
+    let k3 = dynamics::derivatives_with_wind(&state.apply(&k2, dt * 0.5), mission, cmd, wind);
+    let k4 = dynamics::derivatives_with_wind(&state.apply(&k3, dt), mission, cmd, wind);
+
+    let new_quat_raw = state.quat.quaternion()
+        + (k1.dquat + k2.dquat * 2.0 + k3.dquat * 2.0 + k4.dquat) * (dt / 6.0);
+
+    State {
+        time: state.time + dt,
+        pos: state.pos + (k1.dpos + 2.0 * k2.dpos + 2.0 * k3.dpos + k4.dpos) * (dt / 6.0),
+        vel: state.vel + (k1.dvel + 2.0 * k2.dvel + 2.0 * k3.dvel + k4.dvel) * (dt / 6.0),
+        quat: UnitQuaternion::new_normalize(new_quat_raw),
+        omega: state.omega
+            + (k1.domega + 2.0 * k2.domega + 2.0 * k3.domega + k4.domega) * (dt / 6.0),
+        mass: (state.mass
+            + (k1.dmass + 2.0 * k2.dmass + 2.0 * k3.dmass + k4.dmass) * (dt / 6.0))
+            .max(0.0),
+        stage_idx: state.stage_idx,
+        stage_ignition_time: state.stage_ignition_time,
+    }
+}
+
+/// Same as [`rk4_step_with_wind`], but the wind comes from a (possibly
+/// gusty) [`WindField`] instead of a fixed [`WindModel`]. A turbulence
+/// field's shaping filter is a physical process stepped once per `dt`, not
+/// once per RK4 stage, so it is sampled a single time up front (frozen for
+/// the step) rather than re-sampled at each of the 4 intermediate states —
+/// the resulting constant-for-this-step wind vector is then run through the
+/// existing [`rk4_step_with_wind`] exactly like any other `WindModel`.
+pub fn rk4_step_with_field(
+    state: &State,
+    mission: &Mission,
+    cmd: &GncCommand,
+    dt: f64,
+    field: &mut dyn WindField,
+) -> State {
+    let alt = state.pos.z.max(0.0);
+    let wind_vec = field.sample(alt, state.vel.norm(), dt);
+    rk4_step_with_wind(state, mission, cmd, dt, &WindModel::Constant(wind_vec))
+}
+
+// ---------------------------------------------------------------------------
+// Adaptive-step 6DOF integrator: Dormand-Prince 5(4) with error control
+// ---------------------------------------------------------------------------
+//
+// Embedded RK45 so long coast/descent phases can take large steps while
+// high-thrust/staging transients get refined automatically. Used behind
+// `rkf45_step` by callers that opt in (see `sim::runner::simulate_with_adaptive`)
+// rather than folded into `rk4_step`/`simulate_with`, keeping the fixed-step
+// path untouched for existing callers.
+
+/// Weighted sum of derivatives, used to build each Dormand-Prince stage.
+fn deriv_combo(terms: &[(f64, &Deriv)]) -> Deriv {
+    let mut out = Deriv {
+        dpos: Vector3::zeros(),
+        dvel: Vector3::zeros(),
+        dquat: Quaternion::new(0.0, 0.0, 0.0, 0.0),
+        domega: Vector3::zeros(),
+        dmass: 0.0,
+    };
+    for (w, k) in terms {
+        out.dpos += k.dpos * *w;
+        out.dvel += k.dvel * *w;
+        out.dquat += k.dquat * *w;
+        out.domega += k.domega * *w;
+        out.dmass += k.dmass * *w;
+    }
+    out
+}
+
+/// Component-wise normalized error between the 5th- and 4th-order solutions:
+/// `sqrt(mean((Δ_i / sc_i)^2))` with `sc_i = atol + rtol*max(|y5_i|, |y4_i|)`,
+/// covering position, velocity, omega, mass and the quaternion components.
+fn embedded_error_norm(y5: &State, y4: &State, adaptive: &AdaptiveConfig) -> f64 {
+    let mut sum_sq = 0.0;
+    let mut n = 0usize;
+    let mut term = |a: f64, b: f64| {
+        let sc = adaptive.atol + adaptive.rtol * a.abs().max(b.abs());
+        let r = (a - b) / sc;
+        sum_sq += r * r;
+        n += 1;
+    };
+    for i in 0..3 {
+        term(y5.pos[i], y4.pos[i]);
+        term(y5.vel[i], y4.vel[i]);
+        term(y5.omega[i], y4.omega[i]);
+    }
+    term(y5.mass, y4.mass);
+    let q5 = y5.quat.quaternion();
+    let q4 = y4.quat.quaternion();
+    term(q5.w, q4.w);
+    term(q5.i, q4.i);
+    term(q5.j, q4.j);
+    term(q5.k, q4.k);
+    (sum_sq / n as f64).sqrt()
+}
+
+/// Result of one accepted adaptive step: the new state, the step size that
+/// was actually used, and the step size recommended for the next call.
+pub struct AdaptiveStepResult {
+    pub state: State,
+    pub dt_used: f64,
+    pub dt_next: f64,
+}
+
+/// Single adaptive step via the Dormand-Prince 5(4) tableau, retrying with a
+/// shrunk step on rejection until the local error estimate is within
+/// tolerance (or `dt` bottoms out at `adaptive.dt_min`, at which point the
+/// step is accepted anyway rather than looping forever).
+pub fn rkf45_step(
+    state: &State,
+    mission: &Mission,
+    cmd: &GncCommand,
+    dt_guess: f64,
+    adaptive: &AdaptiveConfig,
+) -> AdaptiveStepResult {
+    let mut dt = dt_guess.clamp(adaptive.dt_min, adaptive.dt_max);
+
+    loop {
+        let k1 = dynamics::derivatives(state, mission, cmd);
+        let k2 = dynamics::derivatives(&state.apply(&k1, dt * (1.0 / 5.0)), mission, cmd);
+
+        let s3 = deriv_combo(&[(3.0 / 40.0, &k1), (9.0 / 40.0, &k2)]);
+        let k3 = dynamics::derivatives(&state.apply(&s3, dt), mission, cmd);
+
+        let s4 = deriv_combo(&[(44.0 / 45.0, &k1), (-56.0 / 15.0, &k2), (32.0 / 9.0, &k3)]);
+        let k4 = dynamics::derivatives(&state.apply(&s4, dt), mission, cmd);
+
+        let s5 = deriv_combo(&[
+            (19372.0 / 6561.0, &k1),
+            (-25360.0 / 2187.0, &k2),
+            (64448.0 / 6561.0, &k3),
+            (-212.0 / 729.0, &k4),
+        ]);
+        let k5 = dynamics::derivatives(&state.apply(&s5, dt), mission, cmd);
+
+        let s6 = deriv_combo(&[
+            (9017.0 / 3168.0, &k1),
+            (-355.0 / 33.0, &k2),
+            (46732.0 / 5247.0, &k3),
+            (49.0 / 176.0, &k4),
+            (-5103.0 / 18656.0, &k5),
+        ]);
+        let k6 = dynamics::derivatives(&state.apply(&s6, dt), mission, cmd);
+
+        // 5th-order (FSAL) solution.
+        let s7 = deriv_combo(&[
+            (35.0 / 384.0, &k1),
+            (500.0 / 1113.0, &k3),
+            (125.0 / 192.0, &k4),
+            (-2187.0 / 6784.0, &k5),
+            (11.0 / 84.0, &k6),
+        ]);
+        let y5 = state.apply(&s7, dt);
+        let k7 = dynamics::derivatives(&y5, mission, cmd);
+
+        // Embedded 4th-order solution, for error estimation only.
+        let s4th = deriv_combo(&[
+            (5179.0 / 57600.0, &k1),
+            (7571.0 / 16695.0, &k3),
+            (393.0 / 640.0, &k4),
+            (-92097.0 / 339200.0, &k5),
+            (187.0 / 2100.0, &k6),
+            (1.0 / 40.0, &k7),
+        ]);
+        let y4 = state.apply(&s4th, dt);
+
+        let err_norm = embedded_error_norm(&y5, &y4, adaptive).max(1e-300);
+        let growth = (adaptive.safety * err_norm.powf(-1.0 / 5.0)).clamp(0.2, 5.0);
+        let dt_next = (dt * growth).clamp(adaptive.dt_min, adaptive.dt_max);
+
+        if err_norm <= 1.0 || dt <= adaptive.dt_min + 1e-12 {
+            return AdaptiveStepResult { state: y5, dt_used: dt, dt_next };
+        }
+        dt = dt_next;
     }
 }
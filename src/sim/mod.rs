@@ -1,6 +1,13 @@
 pub mod integrator;
 pub mod runner;
 pub mod event;
+mod drag_loop;
+pub mod recovery;
+pub mod phase;
+pub mod handoff;
 
-pub use runner::{simulate, simulate_with};
-pub use integrator::rk4_step;
+pub use runner::{simulate, simulate_with, simulate_with_adaptive, simulate_with_field, simulate_with_launch, RailDeparture};
+pub use integrator::{rk4_step, rk4_step_with_field, rk4_step_with_wind, rkf45_step};
+pub use recovery::{simulate_with_recovery, DeployTrigger, Parachute};
+pub use phase::{simulate_with_events, ApogeeEvent, DrogueChute, FlightEvent, FlightEventRecord, MainChute, TimedCoast};
+pub use handoff::{ascent_to_orbit, launch_frame_to_eci};
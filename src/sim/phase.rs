@@ -0,0 +1,258 @@
+use std::cell::RefCell;
+
+use crate::dynamics::state::{GncCommand, SimConfig, State};
+use crate::gnc::Controller;
+use crate::vehicle::Mission;
+use super::drag_loop::drag_event_loop;
+use super::integrator::rk4_step;
+use super::recovery::blend_chute_drag;
+
+// ---------------------------------------------------------------------------
+// Event-driven flight-phase engine
+// ---------------------------------------------------------------------------
+//
+// A more general counterpart to `sim::recovery`'s concrete `Parachute` list:
+// `FlightEvent` is a trait object so callers can mix apogee/altitude/timed
+// triggers (and anything else) with arbitrary one-time state effects and
+// dynamics modifiers, and the loop bisects each step so a reported event
+// time isn't just "whichever step happened to straddle it". The stepping
+// loop itself is [`super::drag_loop::drag_event_loop`], shared with
+// `sim::recovery`; only the event bisection below is specific to this
+// trait-object variant.
+
+/// A one-shot condition during flight (apogee, a recovery deployment,
+/// a timed coast marker, ...).
+pub trait FlightEvent {
+    /// Pure predicate: has the condition been crossed going from `prev` to
+    /// `current`? Must be safe to re-evaluate at arbitrary intermediate
+    /// states, since the engine bisects the step to locate the crossing.
+    fn condition(&self, prev: &State, current: &State) -> bool;
+
+    /// One-time effect applied to the state at the moment this event fires.
+    /// Default: no-op (pure marker events like apogee/timed-coast).
+    fn apply(&self, state: &mut State) {
+        let _ = state;
+    }
+
+    /// Dynamics modifier folded into the active stage's (cd, area) once this
+    /// event has fired, applied every step thereafter. Default: no-op.
+    /// Several fired events compose by chaining (each sees the previous
+    /// modifier's output); [`super::recovery::blend_chute_drag`] is the
+    /// shared cd*area-preserving combination.
+    fn modify_stage(&self, cd: f64, area: f64) -> (f64, f64) {
+        (cd, area)
+    }
+
+    fn name(&self) -> String;
+}
+
+/// Fires once when vertical velocity changes from climbing to descending.
+pub struct ApogeeEvent;
+
+impl FlightEvent for ApogeeEvent {
+    fn condition(&self, prev: &State, current: &State) -> bool {
+        prev.vel.z > 0.0 && current.vel.z <= 0.0 && current.pos.z > 100.0
+    }
+
+    fn name(&self) -> String {
+        "Apogee".into()
+    }
+}
+
+/// Deploys at apogee, swapping in the drogue's cd/area.
+pub struct DrogueChute {
+    pub cd_chute: f64,
+    pub area_chute: f64,
+}
+
+impl FlightEvent for DrogueChute {
+    fn condition(&self, prev: &State, current: &State) -> bool {
+        ApogeeEvent.condition(prev, current)
+    }
+
+    fn modify_stage(&self, cd: f64, area: f64) -> (f64, f64) {
+        blend_chute_drag(cd, area, self.cd_chute * self.area_chute)
+    }
+
+    fn name(&self) -> String {
+        "DrogueChute".into()
+    }
+}
+
+/// Deploys on a descending crossing of `altitude` (meters), swapping in the
+/// main chute's cd/area.
+pub struct MainChute {
+    pub altitude: f64,
+    pub cd_chute: f64,
+    pub area_chute: f64,
+}
+
+impl FlightEvent for MainChute {
+    fn condition(&self, prev: &State, current: &State) -> bool {
+        prev.pos.z > self.altitude && current.pos.z <= self.altitude
+    }
+
+    fn modify_stage(&self, cd: f64, area: f64) -> (f64, f64) {
+        blend_chute_drag(cd, area, self.cd_chute * self.area_chute)
+    }
+
+    fn name(&self) -> String {
+        "MainChute".into()
+    }
+}
+
+/// Fires once mission time passes `at` seconds — a marker for a coast phase
+/// boundary, a backup timer, or anything purely time-triggered.
+pub struct TimedCoast {
+    pub at: f64,
+}
+
+impl FlightEvent for TimedCoast {
+    fn condition(&self, prev: &State, current: &State) -> bool {
+        prev.time < self.at && current.time >= self.at
+    }
+
+    fn name(&self) -> String {
+        format!("TimedCoast@{:.1}s", self.at)
+    }
+}
+
+/// A recorded firing of a [`FlightEvent`].
+#[derive(Debug, Clone)]
+pub struct FlightEventRecord {
+    pub time: f64,
+    pub name: String,
+    pub state: State,
+}
+
+/// Bisect within `[0, dt]` of `prev` for the state at which `event.condition`
+/// first crosses, to a fixed resolution (20 halvings, dt/2^20) rather than
+/// just reporting whichever end of the full step straddled it.
+fn bisect_event_state(prev: &State, mission: &Mission, cmd: &GncCommand, dt: f64, event: &dyn FlightEvent) -> State {
+    let mut lo = 0.0;
+    let mut hi = dt;
+    let mut hi_state = rk4_step(prev, mission, cmd, hi);
+    for _ in 0..20 {
+        let mid = 0.5 * (lo + hi);
+        let mid_state = rk4_step(prev, mission, cmd, mid);
+        if event.condition(prev, &mid_state) {
+            hi = mid;
+            hi_state = mid_state;
+        } else {
+            lo = mid;
+        }
+    }
+    hi_state
+}
+
+/// Simulate `mission` with `controller`, watching `events` for their trigger
+/// conditions. Firing is one-shot per event (in index order matches input
+/// order) and each fired event's `modify_stage` is folded into the active
+/// stage's (cd, area) for every subsequent step. Returns the trajectory and
+/// the fired events in firing order.
+pub fn simulate_with_events(
+    mission: &Mission,
+    config: &SimConfig,
+    controller: &mut dyn Controller,
+    events: &[Box<dyn FlightEvent>],
+) -> (Vec<State>, Vec<FlightEventRecord>) {
+    let fired: RefCell<Vec<bool>> = RefCell::new(vec![false; events.len()]);
+    let mut records = Vec::new();
+
+    let trajectory = drag_event_loop(
+        mission,
+        config,
+        controller,
+        true,
+        |state| {
+            if let Some(stage) = mission.stages.get(state.stage_idx) {
+                let (mut cd, mut area) = (stage.cd, stage.area);
+                for (ev, f) in events.iter().zip(fired.borrow().iter()) {
+                    if *f {
+                        let (c, a) = ev.modify_stage(cd, area);
+                        cd = c;
+                        area = a;
+                    }
+                }
+                (cd, area)
+            } else {
+                (0.0, 0.0)
+            }
+        },
+        |prev, next, mission_step, cmd| {
+            for (i, event) in events.iter().enumerate() {
+                if fired.borrow()[i] {
+                    continue;
+                }
+                if event.condition(prev, next) {
+                    let mut at_event = bisect_event_state(prev, mission_step, cmd, config.dt, event.as_ref());
+                    event.apply(&mut at_event);
+                    fired.borrow_mut()[i] = true;
+                    records.push(FlightEventRecord { time: at_event.time, name: event.name(), state: at_event });
+                }
+            }
+        },
+    );
+
+    (trajectory, records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gnc::TvcController;
+    use crate::vehicle::presets;
+
+    #[test]
+    fn apogee_and_chutes_fire_in_order() {
+        let mission = presets::pathfinder();
+        let config = SimConfig { dt: 0.01, max_time: 200.0 };
+        let events: Vec<Box<dyn FlightEvent>> = vec![
+            Box::new(ApogeeEvent),
+            Box::new(DrogueChute { cd_chute: 1.5, area_chute: 0.3 }),
+            Box::new(MainChute { altitude: 1000.0, cd_chute: 2.2, area_chute: 3.0 }),
+        ];
+        let mut controller = TvcController::new();
+        let (_, records) = simulate_with_events(&mission, &config, &mut controller, &events);
+
+        assert_eq!(records.len(), 3, "apogee, drogue and main should all fire");
+        assert_eq!(records[0].name, "Apogee");
+        assert!(records[0].time <= records[1].time);
+        assert!(records[1].time <= records[2].time);
+    }
+
+    #[test]
+    fn chutes_slow_descent_vs_ballistic() {
+        let mission = presets::pathfinder();
+        let config = SimConfig { dt: 0.01, max_time: 200.0 };
+
+        let mut ballistic = TvcController::new();
+        let (ballistic_traj, _) = simulate_with_events(&mission, &config, &mut ballistic, &[]);
+
+        let events: Vec<Box<dyn FlightEvent>> = vec![
+            Box::new(DrogueChute { cd_chute: 1.5, area_chute: 0.3 }),
+            Box::new(MainChute { altitude: 1000.0, cd_chute: 2.2, area_chute: 3.0 }),
+        ];
+        let mut recovered = TvcController::new();
+        let (recovered_traj, _) = simulate_with_events(&mission, &config, &mut recovered, &events);
+
+        let ballistic_landing = ballistic_traj.last().unwrap().vel.norm();
+        let recovered_landing = recovered_traj.last().unwrap().vel.norm();
+        assert!(recovered_landing < ballistic_landing);
+    }
+
+    #[test]
+    fn bisection_reports_accurate_apogee_time() {
+        let mission = presets::pathfinder();
+        let config = SimConfig { dt: 0.05, max_time: 200.0 };
+        let events: Vec<Box<dyn FlightEvent>> = vec![Box::new(ApogeeEvent)];
+        let mut controller = TvcController::new();
+        let (traj, records) = simulate_with_events(&mission, &config, &mut controller, &events);
+
+        // The bisected apogee altitude should sit at (or just past) the true
+        // peak, not merely match whichever coarse 0.05s sample is closest.
+        let max_alt = traj.iter().map(|s| s.pos.z).fold(0.0_f64, f64::max);
+        let apogee_alt = records[0].state.pos.z;
+        assert!((apogee_alt - max_alt).abs() / max_alt < 0.01);
+    }
+}
@@ -0,0 +1,203 @@
+use std::cell::RefCell;
+
+use crate::dynamics::state::{SimConfig, State};
+use crate::gnc::Controller;
+use crate::vehicle::Mission;
+use super::event::{AltitudeDetector, ApogeeDetector, EventDetector, EventKind, SimEvent};
+use super::drag_loop::drag_event_loop;
+
+// ---------------------------------------------------------------------------
+// Parachute/recovery subsystem
+// ---------------------------------------------------------------------------
+//
+// Extends the passive `event` detectors into an actuated system: each step,
+// `simulate_with_recovery` queries every `Parachute`'s trigger against the
+// previous/current state, and once deployed (after its `lag`) folds the
+// chute's drag into the active stage's effective cd/area for that step —
+// summed across multiple deployed chutes so a drogue->main staging produces
+// a two-stage descent instead of a ballistic one. The stepping loop itself
+// is [`super::drag_loop::drag_event_loop`], shared with `sim::phase`'s
+// trait-object `FlightEvent` variant; [`blend_chute_drag`] is the one
+// cd*area-preserving blend formula both use.
+
+/// What causes a parachute to start deploying.
+#[derive(Debug, Clone, Copy)]
+pub enum DeployTrigger {
+    /// Reuses [`ApogeeDetector`]'s vertical-velocity-sign-change logic.
+    Apogee,
+    /// Reuses [`super::event::AltitudeDetector`]'s descending-altitude crossing.
+    AltitudeDescending(f64),
+    /// Fires a fixed time after mission start (e.g. a backup drogue timer).
+    TimeAfter(f64),
+}
+
+/// One parachute: when and how hard it drags once deployed.
+#[derive(Debug, Clone)]
+pub struct Parachute {
+    pub name: String,
+    pub trigger: DeployTrigger,
+    pub cd_chute: f64,
+    pub area_chute: f64,
+    pub lag: f64, // seconds between trigger firing and full deployment
+}
+
+struct Deployment {
+    index: usize,
+    trigger_time: f64,
+}
+
+/// Per-parachute trigger-watching state, built once from its [`DeployTrigger`]
+/// so repeated `AltitudeDetector`/`ApogeeDetector` checks stay stateful
+/// (e.g. "only fire once") across steps.
+enum TriggerState {
+    Apogee(ApogeeDetector),
+    Altitude(AltitudeDetector),
+    Time(f64),
+}
+
+fn trigger_state_for(trigger: DeployTrigger) -> TriggerState {
+    match trigger {
+        DeployTrigger::Apogee => TriggerState::Apogee(ApogeeDetector),
+        DeployTrigger::AltitudeDescending(alt) => TriggerState::Altitude(AltitudeDetector::new(alt, false)),
+        DeployTrigger::TimeAfter(t) => TriggerState::Time(t),
+    }
+}
+
+fn trigger_fired(state: &mut TriggerState, prev: &State, current: &State) -> bool {
+    match state {
+        TriggerState::Apogee(d) => matches!(d.check(prev, current), Some(EventKind::Apogee)),
+        TriggerState::Altitude(d) => d.check(prev, current).is_some(),
+        TriggerState::Time(t) => prev.time < *t && current.time >= *t,
+    }
+}
+
+/// Fold a total chute cd*area contribution into (base_cd, base_area) while
+/// keeping the cd*area drag product physically consistent:
+/// `eff_cd * eff_area = base_cd*base_area + chute_cda`. Shared with
+/// `sim::phase`, which folds one chute's contribution at a time as each
+/// `FlightEvent` fires rather than summing a whole deployed list at once.
+pub(crate) fn blend_chute_drag(base_cd: f64, base_area: f64, chute_cda: f64) -> (f64, f64) {
+    if chute_cda <= 0.0 {
+        return (base_cd, base_area);
+    }
+    let eff_area = base_area + chute_cda / base_cd.max(1e-6);
+    let eff_cd = (base_cd * base_area + chute_cda) / eff_area;
+    (eff_cd, eff_area)
+}
+
+/// Combined (cd, area) of the active stage with any deployed-and-settled
+/// chutes folded in, summing every settled chute's cd*area contribution
+/// before blending it in via [`blend_chute_drag`].
+fn effective_drag(base_cd: f64, base_area: f64, parachutes: &[Parachute], deployed: &[Deployment], time: f64) -> (f64, f64) {
+    let chute_cda: f64 = deployed
+        .iter()
+        .filter(|d| time - d.trigger_time >= parachutes[d.index].lag)
+        .map(|d| parachutes[d.index].cd_chute * parachutes[d.index].area_chute)
+        .sum();
+    blend_chute_drag(base_cd, base_area, chute_cda)
+}
+
+/// Simulate `mission` with `controller`, actuating `parachutes` as their
+/// triggers fire. Returns the trajectory and the recovery-relevant
+/// [`SimEvent`]s (deployments), in addition to whatever ground-impact
+/// behavior [`super::runner::simulate_with`] already provides.
+pub fn simulate_with_recovery(
+    mission: &Mission,
+    config: &SimConfig,
+    controller: &mut dyn Controller,
+    parachutes: &[Parachute],
+) -> (Vec<State>, Vec<SimEvent>) {
+    let mut trigger_states: Vec<TriggerState> = parachutes.iter().map(|p| trigger_state_for(p.trigger)).collect();
+    let deployed: RefCell<Vec<Deployment>> = RefCell::new(Vec::new());
+    let mut events = Vec::new();
+
+    let trajectory = drag_event_loop(
+        mission,
+        config,
+        controller,
+        false, // this loop predates staging support and doesn't advance stages
+        |state| {
+            if let Some(stage) = mission.stages.get(state.stage_idx) {
+                effective_drag(stage.cd, stage.area, parachutes, &deployed.borrow(), state.time)
+            } else {
+                (0.0, 0.0)
+            }
+        },
+        |prev, next, _mission_step, _cmd| {
+            for (i, chute) in parachutes.iter().enumerate() {
+                let already_deployed = deployed.borrow().iter().any(|d| d.index == i);
+                if !already_deployed && trigger_fired(&mut trigger_states[i], prev, next) {
+                    deployed.borrow_mut().push(Deployment { index: i, trigger_time: next.time });
+                    events.push(SimEvent {
+                        time: next.time,
+                        kind: EventKind::Custom(format!("{} deploy", chute.name)),
+                        state: next.clone(),
+                    });
+                }
+            }
+        },
+    );
+
+    (trajectory, events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gnc::TvcController;
+    use crate::vehicle::presets;
+
+    #[test]
+    fn drogue_and_main_reduce_landing_speed() {
+        let mission = presets::pathfinder();
+        let config = SimConfig { dt: 0.01, max_time: 200.0 };
+
+        let mut ballistic = TvcController::new();
+        let (ballistic_traj, _) = simulate_with_recovery(&mission, &config, &mut ballistic, &[]);
+
+        let chutes = vec![
+            Parachute {
+                name: "Drogue".into(),
+                trigger: DeployTrigger::Apogee,
+                cd_chute: 1.5,
+                area_chute: 0.3,
+                lag: 0.5,
+            },
+            Parachute {
+                name: "Main".into(),
+                trigger: DeployTrigger::AltitudeDescending(1000.0),
+                cd_chute: 2.2,
+                area_chute: 3.0,
+                lag: 0.5,
+            },
+        ];
+        let mut recovered = TvcController::new();
+        let (recovered_traj, events) = simulate_with_recovery(&mission, &config, &mut recovered, &chutes);
+
+        let ballistic_landing_speed = ballistic_traj.last().unwrap().vel.norm();
+        let recovered_landing_speed = recovered_traj.last().unwrap().vel.norm();
+        assert!(
+            recovered_landing_speed < ballistic_landing_speed,
+            "chutes should slow the landing: {} vs {}",
+            recovered_landing_speed,
+            ballistic_landing_speed
+        );
+        assert_eq!(events.len(), 2, "both drogue and main should deploy");
+    }
+
+    #[test]
+    fn time_after_trigger_fires_once() {
+        let mission = presets::pathfinder();
+        let config = SimConfig { dt: 0.01, max_time: 60.0 };
+        let chutes = vec![Parachute {
+            name: "Backup".into(),
+            trigger: DeployTrigger::TimeAfter(5.0),
+            cd_chute: 1.0,
+            area_chute: 1.0,
+            lag: 0.0,
+        }];
+        let mut controller = TvcController::new();
+        let (_, events) = simulate_with_recovery(&mission, &config, &mut controller, &chutes);
+        assert_eq!(events.len(), 1);
+    }
+}
@@ -1,10 +1,11 @@
 use nalgebra::{UnitQuaternion, Vector3};
 
-use crate::dynamics::state::{GncCommand, SimConfig, State};
+use crate::dynamics::state::{launch_attitude, AdaptiveConfig, GncCommand, LaunchConfig, SimConfig, State};
+use crate::dynamics::turbulence::WindField;
 use crate::gnc::Controller;
 use crate::gnc::TvcController;
 use crate::vehicle::Mission;
-use super::integrator::rk4_step;
+use super::integrator::{rk4_step, rk4_step_with_field, rk4_step_with_wind, rkf45_step};
 
 // ---------------------------------------------------------------------------
 // Stage separation logic
@@ -12,7 +13,7 @@ use super::integrator::rk4_step;
 
 /// Check if current stage propellant is exhausted, advance to next stage.
 /// Returns updated state with stage_idx incremented and dropped mass removed.
-fn check_staging(state: &mut State, mission: &Mission) {
+pub(crate) fn check_staging(state: &mut State, mission: &Mission) {
     if state.stage_idx >= mission.stages.len() {
         return;
     }
@@ -27,6 +28,7 @@ fn check_staging(state: &mut State, mission: &Mission) {
         // Drop current stage dry mass, advance
         state.mass -= stage.dry_mass;
         state.stage_idx += 1;
+        state.stage_ignition_time = state.time;
     }
 }
 
@@ -34,22 +36,38 @@ fn check_staging(state: &mut State, mission: &Mission) {
 // Full mission simulation
 // ---------------------------------------------------------------------------
 
-/// Simulate a complete multi-stage mission with a custom controller.
-/// Returns trajectory and the GNC commands at each step.
-pub fn simulate_with(
-    mission: &Mission,
-    config: &SimConfig,
-    controller: &mut dyn Controller,
-) -> (Vec<State>, Vec<GncCommand>) {
-    let mut state = State {
+/// Fresh pad-sitting state for `mission`, at the given initial attitude
+/// (identity for a vertical still-air launch, the rail attitude for
+/// [`simulate_with_launch`]). Shared by every `simulate_with*` variant here
+/// and by `sim::recovery`/`sim::phase`'s event-driven loops.
+pub(crate) fn init_state(mission: &Mission, quat: UnitQuaternion<f64>) -> State {
+    State {
         time: 0.0,
         pos: Vector3::zeros(),
         vel: Vector3::zeros(),
-        quat: UnitQuaternion::identity(),
+        quat,
         omega: Vector3::zeros(),
         mass: mission.total_mass(),
         stage_idx: 0,
-    };
+        stage_ignition_time: 0.0,
+    }
+}
+
+/// Shared state-init/trajectory-bookkeeping/launched/ground-impact loop
+/// behind `simulate_with`, `simulate_with_field`, `simulate_with_adaptive`
+/// and `simulate_with_launch`. Each call site supplies `step`, which
+/// integrates one step from `(state, cmd, dt)` — folding in whatever's
+/// specific to it (a wind field, adaptive step control, rail constraint) —
+/// and returns the resulting state plus the dt to use for the next
+/// controller call (only `simulate_with_adaptive` actually varies it).
+fn run_loop(
+    mission: &Mission,
+    config: &SimConfig,
+    init: State,
+    controller: &mut dyn Controller,
+    mut step: impl FnMut(&State, &GncCommand, f64) -> (State, f64),
+) -> (Vec<State>, Vec<GncCommand>) {
+    let mut state = init;
 
     let capacity = (config.max_time / config.dt) as usize + 1;
     let cap = capacity.min(200_000);
@@ -60,22 +78,19 @@ pub fn simulate_with(
     commands.push(GncCommand::default());
 
     let mut launched = false;
+    let mut dt = config.dt;
 
     while state.time < config.max_time {
-        // GNC update
-        let cmd = controller.control(&state, mission, config.dt);
-
-        // Integrate
-        state = rk4_step(&state, mission, &cmd, config.dt);
+        let cmd = controller.control(&state, mission, dt);
 
-        // Stage separation
-        check_staging(&mut state, mission);
+        let (next, next_dt) = step(&state, &cmd, dt);
+        dt = next_dt;
+        state = next;
 
         if state.pos.z > 1.0 {
             launched = true;
         }
 
-        // Ground impact
         if launched && state.pos.z <= 0.0 {
             state.pos.z = 0.0;
             trajectory.push(state);
@@ -90,12 +105,133 @@ pub fn simulate_with(
     (trajectory, commands)
 }
 
+/// Simulate a complete multi-stage mission with a custom controller.
+/// Returns trajectory and the GNC commands at each step.
+pub fn simulate_with(
+    mission: &Mission,
+    config: &SimConfig,
+    controller: &mut dyn Controller,
+) -> (Vec<State>, Vec<GncCommand>) {
+    let init = init_state(mission, UnitQuaternion::identity());
+    run_loop(mission, config, init, controller, |state, cmd, dt| {
+        let mut next = rk4_step(state, mission, cmd, dt);
+        check_staging(&mut next, mission);
+        (next, dt)
+    })
+}
+
+/// Same as [`simulate_with`] but drives drag/angle-of-attack from a
+/// [`WindField`] (e.g. [`DrydenField`] gusts) instead of still air, stepping
+/// with [`rk4_step_with_field`] so the field's shaping-filter state advances
+/// once per sim step.
+pub fn simulate_with_field(
+    mission: &Mission,
+    config: &SimConfig,
+    field: &mut dyn WindField,
+    controller: &mut dyn Controller,
+) -> (Vec<State>, Vec<GncCommand>) {
+    let init = init_state(mission, UnitQuaternion::identity());
+    run_loop(mission, config, init, controller, |state, cmd, dt| {
+        let mut next = rk4_step_with_field(state, mission, cmd, dt, field);
+        check_staging(&mut next, mission);
+        (next, dt)
+    })
+}
+
 /// Simulate with the default TvcController (convenience wrapper).
 pub fn simulate(mission: &Mission, config: &SimConfig) -> (Vec<State>, Vec<GncCommand>) {
     let mut controller = TvcController::new();
     simulate_with(mission, config, &mut controller)
 }
 
+/// Same as [`simulate_with`] but steps with the embedded Dormand-Prince 5(4)
+/// integrator ([`rkf45_step`]) instead of fixed-step RK4, so long coast
+/// phases take large steps while thrust/staging transients self-refine.
+/// `config.dt` is only the initial step-size guess; `adaptive` governs the
+/// tolerances and step bounds actually used.
+pub fn simulate_with_adaptive(
+    mission: &Mission,
+    config: &SimConfig,
+    adaptive: &AdaptiveConfig,
+    controller: &mut dyn Controller,
+) -> (Vec<State>, Vec<GncCommand>) {
+    let init = init_state(mission, UnitQuaternion::identity());
+    run_loop(mission, config, init, controller, |state, cmd, dt_guess| {
+        // Force a step boundary exactly on max_time so the loop terminates
+        // cleanly instead of overshooting into the next adaptive step.
+        let dt_try = dt_guess.min(config.max_time - state.time);
+        let step = rkf45_step(state, mission, cmd, dt_try, adaptive);
+        let mut next = step.state;
+        check_staging(&mut next, mission);
+        (next, step.dt_next)
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Launch rail & surface wind
+// ---------------------------------------------------------------------------
+
+/// Off-rail state recorded the instant the vehicle clears
+/// [`LaunchConfig::rail_length`].
+#[derive(Debug, Clone)]
+pub struct RailDeparture {
+    pub time: f64,
+    pub velocity: Vector3<f64>,
+}
+
+/// Projects `state` onto the rail: zero lateral translation/velocity and a
+/// fixed attitude, leaving only the along-rail (axial) component free. Run
+/// after each ordinary unconstrained step rather than deriving a separate
+/// constrained equation of motion, the same way `sim::recovery`/`sim::phase`
+/// fold their effects onto an otherwise-ordinary step.
+fn constrain_to_rail(state: &State, rail_quat: UnitQuaternion<f64>, rail_axis: Vector3<f64>) -> State {
+    let mut s = state.clone();
+    s.pos = rail_axis * state.pos.dot(&rail_axis);
+    s.vel = rail_axis * state.vel.dot(&rail_axis);
+    s.quat = rail_quat;
+    s.omega = Vector3::zeros();
+    s
+}
+
+/// Same as [`simulate_with`] but launches from a [`LaunchConfig`]: the
+/// vehicle starts at the rail's elevation/azimuth attitude, is constrained to
+/// the rail axis (no lateral drift, fixed attitude) until it has traveled
+/// `rail_length`, and flies with the configured wind injected into drag and
+/// angle-of-attack for the whole flight (on the rail and after, via
+/// [`rk4_step_with_wind`]). Returns the trajectory, commands, and the
+/// rail-departure record if the vehicle cleared the rail (`None` if it never
+/// did, e.g. it ran out of thrust still constrained).
+pub fn simulate_with_launch(
+    mission: &Mission,
+    config: &SimConfig,
+    launch: &LaunchConfig,
+    controller: &mut dyn Controller,
+) -> (Vec<State>, Vec<GncCommand>, Option<RailDeparture>) {
+    let rail_quat = launch_attitude(launch.elevation, launch.azimuth);
+    let rail_axis = rail_quat * Vector3::z();
+    let init = init_state(mission, rail_quat);
+
+    let mut on_rail = launch.rail_length > 0.0;
+    let mut departure = None;
+
+    let (trajectory, commands) = run_loop(mission, config, init, controller, |state, cmd, dt| {
+        let mut next = rk4_step_with_wind(state, mission, cmd, dt, &launch.wind);
+        check_staging(&mut next, mission);
+
+        if on_rail {
+            next = constrain_to_rail(&next, rail_quat, rail_axis);
+            if next.pos.dot(&rail_axis) >= launch.rail_length {
+                on_rail = false;
+                departure = Some(RailDeparture { time: next.time, velocity: next.vel });
+            }
+        }
+
+        (next, dt)
+    });
+
+    (trajectory, commands, departure)
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -103,7 +239,7 @@ pub fn simulate(mission: &Mission, config: &SimConfig) -> (Vec<State>, Vec<GncCo
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::vehicle::Stage;
+    use crate::vehicle::{Engine, Stage};
 
     fn two_stage_mission() -> Mission {
         Mission {
@@ -113,12 +249,10 @@ mod tests {
                     name: "Booster".into(),
                     dry_mass: 40.0,
                     propellant_mass: 30.0,
-                    thrust: 5000.0,
-                    isp: 220.0,
+                    engines: vec![Engine::new(5000.0, 220.0, 1.5)],
                     cd: 0.35,
                     area: 0.02,
                     inertia: Vector3::new(20.0, 20.0, 2.0),
-                    nozzle_offset: 1.5,
                     cp_offset: 0.4,
                     tvc_max: 0.1,
                 },
@@ -126,12 +260,10 @@ mod tests {
                     name: "Sustainer".into(),
                     dry_mass: 10.0,
                     propellant_mass: 8.0,
-                    thrust: 1500.0,
-                    isp: 250.0,
+                    engines: vec![Engine::new(1500.0, 250.0, 0.8)],
                     cd: 0.3,
                     area: 0.01,
                     inertia: Vector3::new(3.0, 3.0, 0.3),
-                    nozzle_offset: 0.8,
                     cp_offset: 0.3,
                     tvc_max: 0.08,
                 },
@@ -146,12 +278,10 @@ mod tests {
                 name: "Main".into(),
                 dry_mass: 20.0,
                 propellant_mass: 10.0,
-                thrust: 2000.0,
-                isp: 220.0,
+                engines: vec![Engine::new(2000.0, 220.0, 1.0)],
                 cd: 0.3,
                 area: 0.008,
                 inertia: Vector3::new(5.0, 5.0, 0.5),
-                nozzle_offset: 1.0,
                 cp_offset: 0.3,
                 tvc_max: 0.1,
             }],
@@ -204,6 +334,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn adaptive_matches_fixed_step_apogee() {
+        let m = single_stage();
+        let config = SimConfig { dt: 0.005, max_time: 300.0 };
+        let (fixed, _) = simulate(&m, &config);
+        let mut controller = TvcController::new();
+        let (adaptive, _) = simulate_with_adaptive(&m, &config, &AdaptiveConfig::default(), &mut controller);
+
+        let apogee_fixed = fixed.iter().map(|s| s.pos.z).fold(0.0_f64, f64::max);
+        let apogee_adaptive = adaptive.iter().map(|s| s.pos.z).fold(0.0_f64, f64::max);
+        let rel_err = (apogee_adaptive - apogee_fixed).abs() / apogee_fixed;
+        assert!(rel_err < 0.05, "adaptive apogee {} should track fixed-step apogee {}", apogee_adaptive, apogee_fixed);
+    }
+
+    #[test]
+    fn adaptive_uses_fewer_steps_during_coast() {
+        let m = single_stage();
+        let config = SimConfig { dt: 0.005, max_time: 300.0 };
+        let (fixed, _) = simulate(&m, &config);
+        let mut controller = TvcController::new();
+        let (adaptive, _) = simulate_with_adaptive(&m, &config, &AdaptiveConfig::default(), &mut controller);
+        assert!(adaptive.len() < fixed.len(), "adaptive stepping should take fewer samples than fixed dt=0.005");
+    }
+
     #[test]
     fn rocket_returns_to_ground() {
         let m = single_stage();
@@ -212,4 +366,52 @@ mod tests {
         let last = traj.last().unwrap();
         assert!(last.pos.z <= 0.01, "Rocket should return to ground");
     }
+
+    #[test]
+    fn rail_constrains_motion_until_departure() {
+        use crate::dynamics::state::LaunchConfig;
+
+        let m = single_stage();
+        let config = SimConfig { dt: 0.005, max_time: 30.0 };
+        let launch = LaunchConfig { rail_length: 5.0, ..LaunchConfig::default() };
+        let mut controller = TvcController::new();
+        let (traj, _, departure) = simulate_with_launch(&m, &config, &launch, &mut controller);
+
+        let departure = departure.expect("vertical rail with plenty of thrust should clear the rail");
+        assert!(departure.velocity.z > 0.0, "should depart moving upward");
+
+        // Every recorded state before departure should show zero lateral drift.
+        for s in traj.iter().take_while(|s| s.time < departure.time) {
+            assert!(s.pos.x.abs() < 1e-9 && s.pos.y.abs() < 1e-9, "rail should forbid lateral translation");
+        }
+    }
+
+    #[test]
+    fn zero_rail_length_behaves_like_plain_launch() {
+        use crate::dynamics::state::LaunchConfig;
+
+        let m = single_stage();
+        let config = SimConfig { dt: 0.005, max_time: 300.0 };
+        let launch = LaunchConfig { rail_length: 0.0, ..LaunchConfig::default() };
+        let mut controller = TvcController::new();
+        let (with_rail, _, departure) = simulate_with_launch(&m, &config, &launch, &mut controller);
+
+        assert!(departure.is_none(), "zero rail length means no rail phase to depart from");
+        let apogee = with_rail.iter().map(|s| s.pos.z).fold(0.0_f64, f64::max);
+        assert!(apogee > 1_000.0);
+    }
+
+    #[test]
+    fn gusty_flight_still_reaches_apogee() {
+        use crate::dynamics::turbulence::{DrydenField, SteadyWind, TurbulenceConfig};
+
+        let m = single_stage();
+        let config = SimConfig { dt: 0.005, max_time: 300.0 };
+        let mut field = DrydenField::new(SteadyWind::calm(), TurbulenceConfig::default());
+        let mut controller = TvcController::new();
+        let (traj, _) = simulate_with_field(&m, &config, &mut field, &mut controller);
+
+        let apogee = traj.iter().map(|s| s.pos.z).fold(0.0_f64, f64::max);
+        assert!(apogee > 1_000.0, "gusty flight should still fly, got apogee {}", apogee);
+    }
 }
@@ -1,5 +1,6 @@
 use nalgebra::Vector3;
 
+use crate::dynamics::state::G0;
 use super::stage::{Stage, StageBuilder};
 
 // ---------------------------------------------------------------------------
@@ -32,6 +33,76 @@ impl Mission {
     pub fn active_stage(&self, idx: usize) -> Option<&Stage> {
         self.stages.get(idx)
     }
+
+    /// Total delta-v achievable if an extra `payload` mass rides above the top stage.
+    fn total_delta_v_with_payload(&self, payload: f64) -> f64 {
+        let mut dv = 0.0;
+        for i in 0..self.stages.len() {
+            let upper: f64 = self.stages[i + 1..].iter().map(|s| s.total_mass()).sum();
+            dv += self.stages[i].delta_v(upper + payload);
+        }
+        dv
+    }
+
+    /// Solve for the maximum payload mass this stack can carry to `target_dv`.
+    ///
+    /// Delta-v decreases monotonically as payload grows, so we bisect on
+    /// payload mass between 0 and an upper bound until the achieved delta-v
+    /// matches `target_dv` within tolerance. Returns `None` if even zero
+    /// payload falls short of the target.
+    pub fn max_payload_for_delta_v(&self, target_dv: f64) -> Option<f64> {
+        const TOL: f64 = 1e-3; // m/s
+        const MAX_ITERS: usize = 100;
+
+        if self.total_delta_v_with_payload(0.0) < target_dv {
+            return None;
+        }
+
+        let mut lo = 0.0;
+        let mut hi = self.total_mass().max(1.0) * 1e6; // generous upper bound
+        // Grow hi until it's a genuine lower bound on achieved dv (or bottoms out).
+        while self.total_delta_v_with_payload(hi) > target_dv {
+            hi *= 2.0;
+        }
+
+        for _ in 0..MAX_ITERS {
+            let mid = 0.5 * (lo + hi);
+            let dv = self.total_delta_v_with_payload(mid);
+            if (dv - target_dv).abs() < TOL {
+                return Some(mid);
+            }
+            if dv > target_dv {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        Some(0.5 * (lo + hi))
+    }
+
+    /// Companion to [`Mission::max_payload_for_delta_v`]: distribute
+    /// `target_dv` across stages by weighting each stage's share by its
+    /// exhaust velocity `ve_i = isp_i * g0` (the textbook Lagrange-multiplier
+    /// result for minimizing total propellant when stages share similar
+    /// structural ratios — a higher-Isp stage should shoulder proportionally
+    /// more of the total delta-v). Returns, per stage, the mass ratio (wet
+    /// mass at ignition / dry mass at cutoff, upper stages counted as
+    /// payload) required to deliver that stage's share.
+    pub fn stage_optimal_mass_ratios(&self, target_dv: f64) -> Vec<f64> {
+        let ve: Vec<f64> = self.stages.iter().map(|s| s.isp() * G0).collect();
+        let ve_sum: f64 = ve.iter().sum();
+        if ve_sum <= 0.0 {
+            return vec![1.0; self.stages.len()];
+        }
+
+        ve.iter()
+            .map(|v| {
+                let dv_share = target_dv * (v / ve_sum);
+                (dv_share / v).exp()
+            })
+            .collect()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -98,3 +169,54 @@ pub mod presets {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_payload_matches_total_delta_v() {
+        let m = presets::pathfinder();
+        let dv = m.total_delta_v();
+        let payload = m.max_payload_for_delta_v(dv).unwrap();
+        assert!(payload < 0.5, "Target = current dv should need ~zero payload, got {}", payload);
+    }
+
+    #[test]
+    fn more_payload_for_lower_target() {
+        let m = presets::pathfinder();
+        let dv = m.total_delta_v();
+        let easy = m.max_payload_for_delta_v(dv * 0.5).unwrap();
+        let hard = m.max_payload_for_delta_v(dv * 0.9).unwrap();
+        assert!(easy > hard, "Lower target dv should allow more payload");
+    }
+
+    #[test]
+    fn unreachable_target_returns_none() {
+        let m = presets::pathfinder();
+        let dv = m.total_delta_v();
+        assert!(m.max_payload_for_delta_v(dv * 10.0).is_none());
+    }
+
+    #[test]
+    fn optimal_mass_ratios_equal_across_stages() {
+        // Under the ve-weighted split, every stage ends up targeting the
+        // same mass ratio — the classic "equal mass ratios" staging result.
+        let m = presets::pathfinder();
+        let target_dv = 3000.0;
+        let ratios = m.stage_optimal_mass_ratios(target_dv);
+        assert_eq!(ratios.len(), m.stages.len());
+        for r in &ratios[1..] {
+            assert!((r - ratios[0]).abs() < 1e-9, "expected equal mass ratios, got {:?}", ratios);
+        }
+        assert!(ratios[0] > 1.0);
+    }
+
+    #[test]
+    fn optimal_mass_ratios_grow_with_target_dv() {
+        let m = presets::pathfinder();
+        let low = m.stage_optimal_mass_ratios(1000.0)[0];
+        let high = m.stage_optimal_mass_ratios(5000.0)[0];
+        assert!(high > low);
+    }
+}
@@ -1,5 +1,9 @@
 pub mod stage;
 pub mod mission;
+pub mod solve;
+pub mod trim;
 
-pub use stage::{Stage, StageBuilder};
+pub use stage::{Engine, Stage, StageBuilder};
 pub use mission::{Mission, MissionBuilder, presets};
+pub use solve::{solve_stage, SizingTargets, SolveConfig, SolveError};
+pub use trim::{solve_trim, TrimConfig, TrimError, TrimTargets};
@@ -0,0 +1,149 @@
+use std::fmt;
+
+use super::stage::Stage;
+
+// ---------------------------------------------------------------------------
+// Damped fixed-point design solver
+// ---------------------------------------------------------------------------
+//
+// A YASim-style "tweak-and-converge" sizing loop: rather than a full gradient
+// solver, each pass nudges a stage's free design parameters in proportion to
+// how far off their coupled metric currently is, damped by `SOLVE_TWEAK` so
+// the iteration doesn't oscillate. Slow but robust, and easy to reason about
+// when a combination of targets turns out to be infeasible.
+
+const SOLVE_TWEAK: f64 = 0.32;
+
+/// Performance targets to size a [`Stage`] against. Unset fields are ignored.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SizingTargets {
+    /// Desired stage delta-v (m/s) carrying `payload_mass` above it. Coupled
+    /// to `propellant_mass`.
+    pub delta_v: Option<f64>,
+    /// Desired burn time (s). Coupled to total engine thrust.
+    pub burn_time: Option<f64>,
+}
+
+/// Tunable knobs of the solve loop.
+#[derive(Debug, Clone, Copy)]
+pub struct SolveConfig {
+    pub tweak: f64,
+    pub threshold: f64,
+    pub max_iters: usize,
+}
+
+impl Default for SolveConfig {
+    fn default() -> Self {
+        Self {
+            tweak: SOLVE_TWEAK,
+            threshold: 1e-3,
+            max_iters: 500,
+        }
+    }
+}
+
+/// The solve didn't converge within `max_iters`; carries the stage as last
+/// left and the relative error of each target so the caller can diagnose an
+/// infeasible combination.
+#[derive(Debug, Clone)]
+pub struct SolveError {
+    pub stage: Stage,
+    pub residuals: Vec<(&'static str, f64)>,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "design solve did not converge; residuals: {:?}", self.residuals)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// `pub(crate)` so `vehicle::trim`'s trim solver can reuse the same
+/// relative-error convention instead of redefining it.
+pub(crate) fn relative_error(current: f64, target: f64) -> f64 {
+    if target == 0.0 {
+        current
+    } else {
+        (target - current) / target
+    }
+}
+
+fn residuals_of(stage: &Stage, payload_mass: f64, targets: &SizingTargets) -> Vec<(&'static str, f64)> {
+    let mut out = Vec::new();
+    if let Some(target) = targets.delta_v {
+        out.push(("delta_v", relative_error(stage.delta_v(payload_mass), target)));
+    }
+    if let Some(target) = targets.burn_time {
+        out.push(("burn_time", relative_error(stage.burn_time(), target)));
+    }
+    out
+}
+
+/// Nudge `stage`'s `propellant_mass` and engine thrust until it simultaneously
+/// meets every target in `targets`, or return a [`SolveError`] with the last
+/// residuals if `config.max_iters` is exhausted first.
+pub fn solve_stage(mut stage: Stage, payload_mass: f64, targets: SizingTargets, config: SolveConfig) -> Result<Stage, SolveError> {
+    for _ in 0..config.max_iters {
+        let residuals = residuals_of(&stage, payload_mass, &targets);
+        if residuals.iter().all(|(_, e)| e.abs() < config.threshold) {
+            return Ok(stage);
+        }
+
+        // More propellant -> more delta-v, so nudge directly with the sign of the error.
+        if let Some(target) = targets.delta_v {
+            let e = relative_error(stage.delta_v(payload_mass), target);
+            stage.propellant_mass = (stage.propellant_mass * (1.0 + config.tweak * e)).max(1e-6);
+        }
+
+        // More thrust burns propellant faster, shortening burn time, so the
+        // nudge sign is inverted relative to delta_v's.
+        if let Some(target) = targets.burn_time {
+            let e = relative_error(stage.burn_time(), target);
+            let scale = (1.0 - config.tweak * e).max(1e-6);
+            for engine in stage.engines.iter_mut() {
+                engine.thrust *= scale;
+            }
+        }
+    }
+
+    Err(SolveError { residuals: residuals_of(&stage, payload_mass, &targets), stage })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vehicle::stage::StageBuilder;
+
+    #[test]
+    fn converges_on_delta_v_target() {
+        let stage = StageBuilder::new("S").dry_mass(20.0).propellant_mass(5.0).thrust(2000.0).isp(220.0).build();
+        let target_dv = stage.delta_v(0.0) * 1.5;
+
+        let sized = solve_stage(stage, 0.0, SizingTargets { delta_v: Some(target_dv), burn_time: None }, SolveConfig::default()).unwrap();
+        assert!((sized.delta_v(0.0) - target_dv).abs() / target_dv < 1e-2);
+    }
+
+    #[test]
+    fn converges_on_combined_targets() {
+        let stage = StageBuilder::new("S").dry_mass(20.0).propellant_mass(10.0).thrust(2000.0).isp(220.0).build();
+        let target_dv = stage.delta_v(0.0) * 1.2;
+        let target_bt = stage.burn_time() * 0.8;
+
+        let targets = SizingTargets { delta_v: Some(target_dv), burn_time: Some(target_bt) };
+        let sized = solve_stage(stage, 0.0, targets, SolveConfig::default()).unwrap();
+
+        assert!((sized.delta_v(0.0) - target_dv).abs() / target_dv < 1e-2);
+        assert!((sized.burn_time() - target_bt).abs() / target_bt < 1e-2);
+    }
+
+    #[test]
+    fn infeasible_targets_report_residuals() {
+        let stage = StageBuilder::new("S").dry_mass(20.0).propellant_mass(5.0).thrust(2000.0).isp(220.0).build();
+        let targets = SizingTargets { delta_v: Some(stage.delta_v(0.0) * 1000.0), burn_time: None };
+        let config = SolveConfig { max_iters: 20, ..SolveConfig::default() };
+
+        let err = solve_stage(stage, 0.0, targets, config).unwrap_err();
+        assert!(!err.residuals.is_empty());
+    }
+}
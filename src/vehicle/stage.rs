@@ -2,6 +2,34 @@ use nalgebra::Vector3;
 
 use crate::dynamics::state::G0;
 
+// ---------------------------------------------------------------------------
+// Engine: one thrust chamber within a stage
+// ---------------------------------------------------------------------------
+
+/// A single engine in a (possibly clustered) stage.
+#[derive(Debug, Clone, Copy)]
+pub struct Engine {
+    pub thrust: f64,            // N, sea-level
+    pub isp: f64,                // s
+    pub burn_time: Option<f64>, // s; None = burns for as long as the stage has propellant
+    pub nozzle_offset: f64,     // distance from CG to nozzle, m
+}
+
+impl Engine {
+    pub fn new(thrust: f64, isp: f64, nozzle_offset: f64) -> Self {
+        Self { thrust, isp, burn_time: None, nozzle_offset }
+    }
+
+    pub fn mass_flow(&self) -> f64 {
+        self.thrust / (self.isp * G0)
+    }
+
+    /// Whether this engine is still contributing thrust at elapsed stage time `t`.
+    pub fn active_at(&self, t: f64) -> bool {
+        self.burn_time.map_or(true, |bt| t < bt)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Stage definition (one stage of a multi-stage rocket)
 // ---------------------------------------------------------------------------
@@ -11,38 +39,137 @@ pub struct Stage {
     pub name: String,
     pub dry_mass: f64,
     pub propellant_mass: f64,
-    pub thrust: f64,              // N
-    pub isp: f64,                 // s
+    pub engines: Vec<Engine>,
     pub cd: f64,
     pub area: f64,                // m^2
     pub inertia: Vector3<f64>,    // [Ixx, Iyy, Izz] principal moments, kg·m^2
-    pub nozzle_offset: f64,       // distance from CG to nozzle, m (positive = nozzle behind CG)
     pub cp_offset: f64,           // distance from CG to CP, m (positive = CP ahead, stable)
     pub tvc_max: f64,             // max gimbal angle, rad
 }
 
 impl Stage {
+    pub fn total_mass(&self) -> f64 {
+        self.dry_mass + self.propellant_mass
+    }
+
+    /// Engines still contributing thrust/mass-flow at elapsed stage time `t`.
+    fn active_engines(&self, t: f64) -> impl Iterator<Item = &Engine> {
+        self.engines.iter().filter(move |e| e.active_at(t))
+    }
+
+    /// Combined thrust of all engines active at elapsed stage time `t`.
+    pub fn thrust_at(&self, t: f64) -> f64 {
+        self.active_engines(t).map(|e| e.thrust).sum()
+    }
+
+    /// Combined propellant mass-flow rate of all engines active at `t`.
+    pub fn mass_flow_at(&self, t: f64) -> f64 {
+        self.active_engines(t).map(|e| e.mass_flow()).sum()
+    }
+
+    /// Thrust-weighted nozzle offset of the engines active at `t`, used as the
+    /// effective moment arm for TVC torque when multiple engines fire together.
+    pub fn nozzle_offset_at(&self, t: f64) -> f64 {
+        let (num, den) = self
+            .active_engines(t)
+            .fold((0.0, 0.0), |(n, d), e| (n + e.thrust * e.nozzle_offset, d + e.thrust));
+        if den > 0.0 { num / den } else { 0.0 }
+    }
+
+    /// Total thrust at stage ignition (t=0), for callers that don't track elapsed time.
+    pub fn thrust(&self) -> f64 {
+        self.thrust_at(0.0)
+    }
+
+    /// Total propellant mass-flow rate at stage ignition (t=0).
     pub fn mass_flow(&self) -> f64 {
-        self.thrust / (self.isp * G0)
+        self.mass_flow_at(0.0)
     }
 
-    pub fn total_mass(&self) -> f64 {
-        self.dry_mass + self.propellant_mass
+    /// Thrust-weighted nozzle offset at stage ignition (t=0).
+    pub fn nozzle_offset(&self) -> f64 {
+        self.nozzle_offset_at(0.0)
+    }
+
+    /// Effective specific impulse of the cluster at stage ignition (t=0),
+    /// i.e. total thrust divided by total mass flow in units of g0.
+    pub fn isp(&self) -> f64 {
+        let mdot = self.mass_flow_at(0.0);
+        if mdot > 0.0 { self.thrust_at(0.0) / (mdot * G0) } else { 0.0 }
+    }
+
+    /// Whether this stage's mass-flow profile is constant for the whole
+    /// burn (single engine, no independent cutoff), so the closed-form
+    /// single-engine formulas apply instead of numerically integrating a
+    /// time-varying cluster profile.
+    fn has_constant_mass_flow(&self) -> bool {
+        matches!(self.engines.as_slice(), [engine] if engine.burn_time.is_none())
     }
 
-    /// Self-consistent burn time from propellant and mass flow.
+    /// Self-consistent burn time: how long it takes this stage's engines to
+    /// deplete `propellant_mass`, accounting for engines dropping out as their
+    /// own `burn_time` elapses.
     pub fn burn_time(&self) -> f64 {
-        if self.thrust > 0.0 {
-            self.propellant_mass / self.mass_flow()
-        } else {
-            0.0
+        if self.engines.is_empty() {
+            return 0.0;
+        }
+        if self.has_constant_mass_flow() {
+            return self.propellant_mass / self.mass_flow_at(0.0);
         }
+
+        const DT: f64 = 0.01;
+        const MAX_T: f64 = 10_000.0;
+
+        let mut remaining = self.propellant_mass;
+        let mut t = 0.0;
+        while remaining > 1e-9 && t < MAX_T {
+            let mdot = self.mass_flow_at(t);
+            if mdot <= 0.0 {
+                break;
+            }
+            remaining -= (mdot * DT).min(remaining);
+            t += DT;
+        }
+        t
     }
 
+    /// Ideal delta-v for this stage carrying `payload_mass` above it.
+    ///
+    /// For the common single-engine, no-cutoff case this is the exact
+    /// closed-form `isp * G0 * ln(m0 / mf)` (constant exhaust velocity, no
+    /// need to integrate). A cluster of engines with independent burn times
+    /// has a time-varying mass-flow profile, so that case is numerically
+    /// integrated instead.
     pub fn delta_v(&self, payload_mass: f64) -> f64 {
+        if self.engines.is_empty() {
+            return 0.0;
+        }
+
         let m0 = self.total_mass() + payload_mass;
         let mf = self.dry_mass + payload_mass;
-        self.isp * G0 * (m0 / mf).ln()
+
+        if self.has_constant_mass_flow() {
+            return self.isp() * G0 * (m0 / mf).ln();
+        }
+
+        const DT: f64 = 0.01;
+        const MAX_T: f64 = 10_000.0;
+
+        let mut mass = m0;
+        let mut dv = 0.0;
+        let mut t = 0.0;
+
+        while mass > mf + 1e-9 && t < MAX_T {
+            let thrust = self.thrust_at(t);
+            let mdot = self.mass_flow_at(t);
+            if thrust <= 0.0 || mdot <= 0.0 {
+                break;
+            }
+            dv += (thrust / mass) * DT;
+            mass -= (mdot * DT).min(mass - mf);
+            t += DT;
+        }
+        dv
     }
 }
 
@@ -54,12 +181,15 @@ pub struct StageBuilder {
     name: String,
     dry_mass: f64,
     propellant_mass: f64,
+    // Single-engine convenience fields, used to synthesize one Engine in
+    // `build()` when no engines were added explicitly via `.engine(...)`.
     thrust: f64,
     isp: f64,
+    nozzle_offset: f64,
+    engines: Vec<Engine>,
     cd: f64,
     area: f64,
     inertia: Vector3<f64>,
-    nozzle_offset: f64,
     cp_offset: f64,
     tvc_max: f64,
 }
@@ -72,10 +202,11 @@ impl StageBuilder {
             propellant_mass: 5.0,
             thrust: 1000.0,
             isp: 220.0,
+            nozzle_offset: 1.0,
+            engines: vec![],
             cd: 0.3,
             area: 0.01,
             inertia: Vector3::new(5.0, 5.0, 0.5),
-            nozzle_offset: 1.0,
             cp_offset: 0.3,
             tvc_max: 0.1,
         }
@@ -83,28 +214,104 @@ impl StageBuilder {
 
     pub fn dry_mass(mut self, v: f64) -> Self { self.dry_mass = v; self }
     pub fn propellant_mass(mut self, v: f64) -> Self { self.propellant_mass = v; self }
+
+    /// Convenience single-engine thrust. Ignored once `.engine(...)` has been used.
     pub fn thrust(mut self, v: f64) -> Self { self.thrust = v; self }
+    /// Convenience single-engine Isp. Ignored once `.engine(...)` has been used.
     pub fn isp(mut self, v: f64) -> Self { self.isp = v; self }
+    /// Convenience single-engine nozzle offset. Ignored once `.engine(...)` has been used.
+    pub fn nozzle_offset(mut self, v: f64) -> Self { self.nozzle_offset = v; self }
+
+    /// Add one engine to the cluster.
+    pub fn engine(mut self, engine: Engine) -> Self {
+        self.engines.push(engine);
+        self
+    }
+
+    /// Add `count` copies of the same engine to the cluster.
+    pub fn engines(mut self, engine: Engine, count: usize) -> Self {
+        self.engines.extend(std::iter::repeat(engine).take(count));
+        self
+    }
+
     pub fn cd(mut self, v: f64) -> Self { self.cd = v; self }
     pub fn area(mut self, v: f64) -> Self { self.area = v; self }
     pub fn inertia(mut self, v: Vector3<f64>) -> Self { self.inertia = v; self }
-    pub fn nozzle_offset(mut self, v: f64) -> Self { self.nozzle_offset = v; self }
     pub fn cp_offset(mut self, v: f64) -> Self { self.cp_offset = v; self }
     pub fn tvc_max(mut self, v: f64) -> Self { self.tvc_max = v; self }
 
     pub fn build(self) -> Stage {
+        let engines = if self.engines.is_empty() {
+            vec![Engine { thrust: self.thrust, isp: self.isp, burn_time: None, nozzle_offset: self.nozzle_offset }]
+        } else {
+            self.engines
+        };
+
         Stage {
             name: self.name,
             dry_mass: self.dry_mass,
             propellant_mass: self.propellant_mass,
-            thrust: self.thrust,
-            isp: self.isp,
+            engines,
             cd: self.cd,
             area: self.area,
             inertia: self.inertia,
-            nozzle_offset: self.nozzle_offset,
             cp_offset: self.cp_offset,
             tvc_max: self.tvc_max,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_engine_builder_matches_explicit_engine() {
+        let a = StageBuilder::new("A").thrust(2000.0).isp(220.0).nozzle_offset(1.0).build();
+        let b = StageBuilder::new("B").engine(Engine::new(2000.0, 220.0, 1.0)).build();
+        assert!((a.thrust() - b.thrust()).abs() < 1e-9);
+        assert!((a.mass_flow() - b.mass_flow()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn clustered_engines_sum_thrust() {
+        let s = StageBuilder::new("Cluster")
+            .propellant_mass(20.0)
+            .engines(Engine::new(1000.0, 220.0, 1.0), 4)
+            .build();
+        assert!((s.thrust_at(0.0) - 4000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn engine_drops_out_after_its_own_burn_time() {
+        let mut booster = Engine::new(1000.0, 220.0, 1.0);
+        booster.burn_time = Some(10.0);
+        let sustainer = Engine::new(500.0, 250.0, 0.5);
+        let s = StageBuilder::new("Mixed")
+            .propellant_mass(50.0)
+            .engine(booster)
+            .engine(sustainer)
+            .build();
+
+        assert!((s.thrust_at(5.0) - 1500.0).abs() < 1e-9, "both engines active before booster burnout");
+        assert!((s.thrust_at(15.0) - 500.0).abs() < 1e-9, "only sustainer active after booster burnout");
+    }
+
+    #[test]
+    fn delta_v_decreases_with_payload() {
+        let s = StageBuilder::new("S").dry_mass(20.0).propellant_mass(10.0).thrust(2000.0).isp(220.0).build();
+        assert!(s.delta_v(0.0) > s.delta_v(10.0));
+    }
+
+    #[test]
+    fn single_engine_delta_v_and_burn_time_match_closed_form() {
+        let s = StageBuilder::new("S").dry_mass(20.0).propellant_mass(10.0).thrust(2000.0).isp(220.0).build();
+        let payload = 5.0;
+
+        let expected_dv = s.isp() * G0 * ((s.total_mass() + payload) / (s.dry_mass + payload)).ln();
+        assert!((s.delta_v(payload) - expected_dv).abs() < 1e-9);
+
+        let expected_bt = s.propellant_mass / s.mass_flow();
+        assert!((s.burn_time() - expected_bt).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+
+use crate::dynamics::state::{SimConfig, State, G0};
+use crate::sim::runner::simulate;
+use super::mission::Mission;
+use super::solve::relative_error;
+use super::stage::Stage;
+
+// ---------------------------------------------------------------------------
+// Trim/stability design solver
+// ---------------------------------------------------------------------------
+//
+// Same YASim-style damped relaxation loop as `vehicle::solve`'s sizing
+// solver (see that module's doc comment for the general approach), reusing
+// its `relative_error` helper, but nudging the parameters that govern
+// trim/stability (CP offset, cluster thrust) toward static-margin and
+// liftoff-TWR targets instead of the ones that govern delta-v/burn-time
+// sizing (propellant mass, thrust scale). Kept as its own module/target
+// struct rather than folded into `SizingTargets` so the two solvers stay
+// independently extensible. `liftoff_twr` is evaluated from a trial
+// single-stage simulation of the candidate `stage`, sampling the liftoff
+// state's actual mass rather than assuming a book value; `static_margin_calibers`
+// is a pure function of `stage`'s static properties (this model has no
+// Mach/AoA-dependent CP shift), so the trial sim only runs when
+// `liftoff_twr` is actually targeted.
+
+const TRIM_TWEAK: f64 = 0.32;
+
+/// Trim/stability targets to size a [`Stage`] against. Unset fields are
+/// ignored.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrimTargets {
+    /// Desired static margin in calibers (`cp_offset / body_length`).
+    /// Coupled to `cp_offset`.
+    pub static_margin_calibers: Option<f64>,
+    /// Desired liftoff thrust-to-weight ratio (`thrust() / (total_mass() * G0)`).
+    /// Coupled to total engine thrust.
+    pub liftoff_twr: Option<f64>,
+}
+
+/// Tunable knobs of the trim solve loop.
+#[derive(Debug, Clone, Copy)]
+pub struct TrimConfig {
+    pub tweak: f64,
+    pub threshold: f64,
+    pub max_iters: usize,
+}
+
+impl Default for TrimConfig {
+    fn default() -> Self {
+        Self {
+            tweak: TRIM_TWEAK,
+            threshold: 1e-3,
+            max_iters: 500,
+        }
+    }
+}
+
+/// The trim solve didn't converge within `max_iters`; carries the stage as
+/// last left and the relative error of each target so the caller can
+/// diagnose an infeasible combination.
+#[derive(Debug, Clone)]
+pub struct TrimError {
+    pub stage: Stage,
+    pub residuals: Vec<(&'static str, f64)>,
+}
+
+impl fmt::Display for TrimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "trim solve did not converge; residuals: {:?}", self.residuals)
+    }
+}
+
+impl std::error::Error for TrimError {}
+
+/// Run a short single-stage trial simulation of `stage` from liftoff through
+/// burnout plus a short coast, so residuals can be sampled from actual
+/// flight states rather than read off book values.
+fn trial_trajectory(stage: &Stage) -> Vec<State> {
+    let mission = Mission { name: "trim-trial".into(), stages: vec![stage.clone()] };
+    let sim_config = SimConfig { dt: 0.05, max_time: stage.burn_time() + 5.0 };
+    let (trajectory, _) = simulate(&mission, &sim_config);
+    trajectory
+}
+
+/// Static margin in calibers. This model's restoring moment is linear in
+/// `cp_offset` with no Mach/AoA-dependent shift, so it's a pure function of
+/// `stage`'s static properties and needs no trial flight state to sample.
+fn static_margin_calibers(stage: &Stage, body_length: f64) -> f64 {
+    stage.cp_offset / body_length
+}
+
+fn residuals_of(stage: &Stage, body_length: f64, targets: &TrimTargets) -> Vec<(&'static str, f64)> {
+    let mut out = Vec::new();
+
+    if let Some(target) = targets.static_margin_calibers {
+        let margin = static_margin_calibers(stage, body_length);
+        out.push(("static_margin_calibers", relative_error(margin, target)));
+    }
+    if let Some(target) = targets.liftoff_twr {
+        let trajectory = trial_trajectory(stage);
+        let liftoff = trajectory.first().expect("trial trajectory always has at least the pad state");
+        let twr = stage.thrust_at(0.0) / (liftoff.mass * G0);
+        out.push(("liftoff_twr", relative_error(twr, target)));
+    }
+    out
+}
+
+/// Nudge `stage`'s `cp_offset` and engine thrust until it simultaneously
+/// meets every target in `targets`, or return a [`TrimError`] with the last
+/// residuals if `config.max_iters` is exhausted first. `body_length` is the
+/// vehicle's reference length (nose to base, m) used to express static
+/// margin in calibers; it isn't stored on `Stage` itself so existing
+/// `Stage`/`StageBuilder` call sites are unaffected.
+pub fn solve_trim(mut stage: Stage, body_length: f64, targets: TrimTargets, config: TrimConfig) -> Result<Stage, TrimError> {
+    for _ in 0..config.max_iters {
+        let residuals = residuals_of(&stage, body_length, &targets);
+        if residuals.iter().all(|(_, e)| e.abs() < config.threshold) {
+            return Ok(stage);
+        }
+        let residual = |name: &str| residuals.iter().find(|(n, _)| *n == name).map(|(_, e)| *e);
+
+        // More CP offset -> more static margin, so nudge directly with the sign of the error.
+        if let Some(e) = residual("static_margin_calibers") {
+            stage.cp_offset = (stage.cp_offset * (1.0 + config.tweak * e)).max(1e-6);
+        }
+
+        // More thrust -> higher TWR, so nudge directly with the sign of the error.
+        if let Some(e) = residual("liftoff_twr") {
+            let scale = (1.0 + config.tweak * e).max(1e-6);
+            for engine in stage.engines.iter_mut() {
+                engine.thrust *= scale;
+            }
+        }
+    }
+
+    Err(TrimError { residuals: residuals_of(&stage, body_length, &targets), stage })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vehicle::stage::StageBuilder;
+
+    #[test]
+    fn converges_on_static_margin_target() {
+        let stage = StageBuilder::new("S").cp_offset(0.1).build();
+        let body_length = 2.0;
+        let targets = TrimTargets { static_margin_calibers: Some(1.5), liftoff_twr: None };
+
+        let trimmed = solve_trim(stage, body_length, targets, TrimConfig::default()).unwrap();
+        assert!((trimmed.cp_offset / body_length - 1.5).abs() < 1e-2);
+    }
+
+    #[test]
+    fn converges_on_combined_trim_targets() {
+        let stage = StageBuilder::new("S").dry_mass(20.0).propellant_mass(10.0).thrust(2000.0).isp(220.0).cp_offset(0.1).build();
+        let body_length = 3.0;
+        let targets = TrimTargets {
+            static_margin_calibers: Some(1.2),
+            liftoff_twr: Some(1.4),
+        };
+
+        let trimmed = solve_trim(stage, body_length, targets, TrimConfig::default()).unwrap();
+        assert!((trimmed.cp_offset / body_length - 1.2).abs() / 1.2 < 1e-2);
+        let twr = trimmed.thrust() / (trimmed.total_mass() * G0);
+        assert!((twr - 1.4).abs() / 1.4 < 1e-2);
+    }
+
+    #[test]
+    fn infeasible_trim_targets_report_residuals() {
+        let stage = StageBuilder::new("S").cp_offset(0.1).build();
+        let targets = TrimTargets { static_margin_calibers: Some(1000.0), liftoff_twr: None };
+        let config = TrimConfig { max_iters: 20, ..TrimConfig::default() };
+
+        let err = solve_trim(stage, 2.0, targets, config).unwrap_err();
+        assert!(!err.residuals.is_empty());
+    }
+}